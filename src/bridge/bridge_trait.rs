@@ -4,7 +4,7 @@
 
 use crate::error::Result;
 use crate::protocol::FinalityThreshold;
-use alloy_chains::NamedChain;
+use alloy_chains::Chain;
 use alloy_primitives::{Address, FixedBytes, TxHash};
 use async_trait::async_trait;
 
@@ -42,12 +42,12 @@ pub trait CctpBridge: Send + Sync {
     /// Returns the source chain for the bridge
     ///
     /// This is the chain where the USDC burn transaction originates.
-    fn source_chain(&self) -> NamedChain;
+    fn source_chain(&self) -> Chain;
 
     /// Returns the destination chain for the bridge
     ///
     /// This is the chain where USDC will be minted after attestation.
-    fn destination_chain(&self) -> NamedChain;
+    fn destination_chain(&self) -> Chain;
 
     /// Returns the recipient address on the destination chain
     ///
@@ -4,23 +4,31 @@
 
 use crate::error::{AttestationFailureKind, CctpError, Result};
 use crate::{spans, DomainId};
-use crate::{AttestationBytes, AttestationResponse, AttestationStatus, CctpV1};
-use alloy_chains::NamedChain;
-use alloy_network::Ethereum;
-use alloy_primitives::{hex, Address, FixedBytes, TxHash};
-use alloy_provider::Provider;
+use crate::{
+    AttestationBytes, AttestationResponse, AttestationStatus, CctpEnvironment, CctpV1, CctpV2,
+    ChainRegistry,
+};
+use alloy_chains::Chain;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{hex, Address, Bytes, FixedBytes, TxHash, U256};
+use alloy_provider::{Provider, WalletProvider};
 use alloy_sol_types::SolEvent;
 use async_trait::async_trait;
 use bon::Builder;
 use reqwest::{Client, Response};
+use std::marker::PhantomData;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, error, info};
 use url::Url;
 
 use super::bridge_trait::CctpBridge;
-use super::config::{PollingConfig, ATTESTATION_PATH_V1, IRIS_API, IRIS_API_SANDBOX};
+use super::config::{PollingConfig, ATTESTATION_PATH_V1};
+use super::v2::{check_sender, wallet_signer};
 use crate::contracts::message_transmitter::MessageTransmitter::MessageSent;
+use crate::contracts::message_transmitter::MessageTransmitterContract;
+use crate::contracts::token_messenger::TokenMessengerContract;
 use crate::protocol::FinalityThreshold;
 
 /// CCTP v1 bridge implementation
@@ -46,57 +54,125 @@ use crate::protocol::FinalityThreshold;
 /// # }
 /// ```
 #[derive(Builder, Clone, Debug)]
-pub struct Cctp<P: Provider<Ethereum> + Clone> {
-    source_provider: P,
-    destination_provider: P,
-    source_chain: NamedChain,
-    destination_chain: NamedChain,
+pub struct Cctp<
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone = SP,
+    SN: Network = Ethereum,
+    DN: Network = SN,
+> {
+    source_provider: SP,
+    destination_provider: DP,
+
+    /// Chain USDC is burned on
+    ///
+    /// A `NamedChain`, or a chain id for chains described in the registry.
+    #[builder(into)]
+    source_chain: Chain,
+
+    /// Chain USDC is minted on, given like `source_chain`
+    #[builder(into)]
+    destination_chain: Chain,
+
     recipient: Address,
+
+    /// Address the source provider signs with
+    ///
+    /// When set, burns and replacements must come from this address.
+    /// [`Self::with_source_wallet`] fills it from, or checks it against, the
+    /// source provider's wallet.
+    source_signer: Option<Address>,
+
+    /// Chain records to resolve the source and destination chains through
+    ///
+    /// Chains without a record fall back to the built-in tables. See
+    /// [`ChainRegistry`].
+    #[builder(into)]
+    registry: Option<Arc<ChainRegistry>>,
+
+    /// Environment to attest through, instead of the source chain's
+    ///
+    /// Only the Iris API URL is used; v1 contracts are always looked up per
+    /// chain.
+    environment: Option<CctpEnvironment>,
+
+    #[builder(skip)]
+    network: PhantomData<(SN, DN)>,
 }
 
-impl<P: Provider<Ethereum> + Clone> Cctp<P> {
+impl<SP, DP, SN, DN> Cctp<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
     /// Returns the CCTP API URL for the current environment
     pub fn api_url(&self) -> Url {
-        if self.source_chain.is_testnet() {
-            Url::parse(IRIS_API_SANDBOX).unwrap()
-        } else {
-            Url::parse(IRIS_API).unwrap()
+        self.environment().iris_api_url()
+    }
+
+    /// Returns the environment the bridge attests through
+    ///
+    /// The builder's `environment`, or else the source chain's, resolved
+    /// through the registry.
+    pub fn environment(&self) -> CctpEnvironment {
+        match (&self.environment, &self.registry) {
+            (Some(environment), _) => environment.clone(),
+            (None, Some(registry)) => registry.environment(self.source_chain),
+            (None, None) => self.source_chain.environment(),
         }
     }
 
     /// Returns the source chain
-    pub fn source_chain(&self) -> &NamedChain {
+    pub fn source_chain(&self) -> &Chain {
         &self.source_chain
     }
 
     /// Returns the destination chain
-    pub fn destination_chain(&self) -> &NamedChain {
+    pub fn destination_chain(&self) -> &Chain {
         &self.destination_chain
     }
 
+    /// Returns the source chain's configuration, resolved through the registry
+    fn source_config(&self) -> &dyn CctpV1 {
+        match &self.registry {
+            Some(registry) => registry.v1(&self.source_chain),
+            None => &self.source_chain,
+        }
+    }
+
+    /// Returns the destination chain's configuration, resolved through the
+    /// registry
+    fn destination_config(&self) -> &dyn CctpV1 {
+        match &self.registry {
+            Some(registry) => registry.v1(&self.destination_chain),
+            None => &self.destination_chain,
+        }
+    }
+
     /// Returns the destination domain id
     pub fn destination_domain_id(&self) -> Result<DomainId> {
-        self.destination_chain.cctp_domain_id()
+        self.destination_config().cctp_domain_id()
     }
 
     /// Returns the source provider
-    pub fn source_provider(&self) -> &P {
+    pub fn source_provider(&self) -> &SP {
         &self.source_provider
     }
 
     /// Returns the destination provider
-    pub fn destination_provider(&self) -> &P {
+    pub fn destination_provider(&self) -> &DP {
         &self.destination_provider
     }
 
     /// Returns the CCTP token messenger contract, the address of the contract that handles the deposit and burn of USDC
     pub fn token_messenger_contract(&self) -> Result<Address> {
-        self.source_chain.token_messenger_address()
+        self.source_config().token_messenger_address()
     }
 
     /// Returns the CCTP message transmitter contract, the address of the contract that handles the receipt of messages
     pub fn message_transmitter_contract(&self) -> Result<Address> {
-        self.destination_chain.message_transmitter_address()
+        self.destination_config().message_transmitter_address()
     }
 
     /// Returns the recipient address
@@ -104,6 +180,16 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
         &self.recipient
     }
 
+    /// Returns the configured source-chain signer, if any
+    pub fn source_signer(&self) -> Option<Address> {
+        self.source_signer
+    }
+
+    /// Returns the chain registry, if any
+    pub fn registry(&self) -> Option<&ChainRegistry> {
+        self.registry.as_deref()
+    }
+
     /// Gets the `MessageSent` event data from a CCTP bridge transaction
     ///
     /// # Arguments
@@ -121,7 +207,7 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
             spans::get_message_sent_event(tx_hash, &self.source_chain, &self.destination_chain);
         let _guard = span.enter();
 
-        let tx_receipt = match self.source_provider.get_transaction_receipt(tx_hash).await {
+        let tx_logs = match super::receipt::transaction_logs(&self.source_provider, tx_hash).await {
             Ok(receipt) => receipt,
             Err(e) => {
                 spans::record_error_with_context(
@@ -137,13 +223,11 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
             }
         };
 
-        if let Some(tx_receipt) = tx_receipt {
+        if let Some(tx_logs) = tx_logs {
             // Calculate the event topic by hashing the event signature
             let message_sent_topic = alloy_primitives::keccak256(b"MessageSent(bytes)");
 
-            let message_sent_log = tx_receipt
-                .inner
-                .logs()
+            let message_sent_log = tx_logs
                 .iter()
                 .find(|log| {
                     log.topics()
@@ -156,11 +240,11 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
                         "MessageSent event not found in transaction logs",
                         Some(&format!(
                             "Transaction contained {} logs but none matched MessageSent signature",
-                            tx_receipt.inner.logs().len()
+                            tx_logs.len()
                         )),
                     );
                     error!(
-                        available_logs = tx_receipt.inner.logs().len(),
+                        available_logs = tx_logs.len(),
                         event = "message_sent_event_not_found"
                     );
                     CctpError::MessageSentEventMissing { tx_hash }
@@ -375,6 +459,219 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
         Err(CctpError::AttestationTimeout)
     }
 
+    /// Burn USDC on the source chain, restricting who may mint on the destination
+    ///
+    /// Sends `depositForBurnWithCaller`, so only `destination_caller` can call
+    /// `receiveMessage` for the resulting message. Use this when a specific
+    /// relayer or contract must complete the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of USDC to transfer (in atomic units, e.g., 1 USDC = `1_000_000`)
+    /// * `from` - Address that will send the transaction (must have USDC balance and gas)
+    /// * `token_address` - USDC token contract address on source chain
+    /// * `destination_caller` - The only address allowed to receive the message on destination
+    ///
+    /// # Returns
+    ///
+    /// The transaction hash of the burn transaction
+    pub async fn burn_with_caller(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        destination_caller: Address,
+    ) -> Result<TxHash> {
+        check_sender("source", self.source_signer, from)?;
+        let tx_request = self.burn_with_caller_transaction_request(
+            amount,
+            from,
+            token_address,
+            destination_caller,
+        )?;
+
+        let pending_tx = self.source_provider.send_transaction(tx_request).await?;
+        let tx_hash = *pending_tx.tx_hash();
+
+        info!(
+            tx_hash = %tx_hash,
+            destination_caller = %destination_caller,
+            version = "v1",
+            event = "burn_with_caller_transaction_sent"
+        );
+
+        Ok(tx_hash)
+    }
+
+    /// Re-target a pending burn to a new mint recipient and destination caller
+    ///
+    /// Sends `replaceDepositForBurn` on the source chain. This is the recovery path
+    /// for a v1 transfer sent to the wrong recipient or to a destination caller that
+    /// cannot (or will not) relay it. The replacement keeps the original nonce, so
+    /// only one of the two messages can ever be received.
+    ///
+    /// After the replacement is mined, fetch its message with
+    /// [`Self::get_message_sent_event`] and poll [`Self::get_attestation`] again.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_message` - The message bytes emitted by the original burn
+    /// * `original_attestation` - Circle's attestation for the original message
+    /// * `new_destination_caller` - New authorized caller on destination (`Address::ZERO` = anyone)
+    /// * `new_mint_recipient` - New recipient of the minted USDC
+    /// * `from` - The original burn sender (the contract rejects anyone else)
+    ///
+    /// # Returns
+    ///
+    /// The transaction hash of the replacement transaction
+    pub async fn replace_deposit_for_burn(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_destination_caller: Address,
+        new_mint_recipient: Address,
+        from: Address,
+    ) -> Result<TxHash> {
+        check_sender("source", self.source_signer, from)?;
+        let tx_request = self.replace_deposit_for_burn_transaction_request(
+            original_message,
+            original_attestation,
+            new_destination_caller,
+            new_mint_recipient,
+            from,
+        )?;
+
+        let pending_tx = self.source_provider.send_transaction(tx_request).await?;
+        let tx_hash = *pending_tx.tx_hash();
+
+        info!(
+            tx_hash = %tx_hash,
+            new_destination_caller = %new_destination_caller,
+            new_mint_recipient = %new_mint_recipient,
+            version = "v1",
+            event = "replace_deposit_for_burn_transaction_sent"
+        );
+
+        Ok(tx_hash)
+    }
+
+    /// Replace the body and destination caller of a previously sent message
+    ///
+    /// Sends `replaceMessage` to the source chain's `MessageTransmitter`. For burn
+    /// messages, prefer [`Self::replace_deposit_for_burn`], which re-encodes the
+    /// burn body for you; this lower-level variant is for generic messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_message` - The original message bytes
+    /// * `original_attestation` - Circle's attestation for the original message
+    /// * `new_message_body` - The replacement message body
+    /// * `new_destination_caller` - New authorized caller on destination (`Address::ZERO` = anyone)
+    /// * `from` - The original message sender
+    ///
+    /// # Returns
+    ///
+    /// The transaction hash of the replacement transaction
+    pub async fn replace_message(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_message_body: Vec<u8>,
+        new_destination_caller: Address,
+        from: Address,
+    ) -> Result<TxHash> {
+        check_sender("source", self.source_signer, from)?;
+        let tx_request = self.replace_message_transaction_request(
+            original_message,
+            original_attestation,
+            new_message_body,
+            new_destination_caller,
+            from,
+        )?;
+
+        let pending_tx = self.source_provider.send_transaction(tx_request).await?;
+        let tx_hash = *pending_tx.tx_hash();
+
+        info!(
+            tx_hash = %tx_hash,
+            new_destination_caller = %new_destination_caller,
+            version = "v1",
+            event = "replace_message_transaction_sent"
+        );
+
+        Ok(tx_hash)
+    }
+
+    /// Builds the `depositForBurnWithCaller` sent by [`Self::burn_with_caller`]
+    pub(super) fn burn_with_caller_transaction_request(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        destination_caller: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let token_messenger = TokenMessengerContract::new(
+            self.token_messenger_contract()?,
+            self.source_provider.clone(),
+        );
+        let destination_domain = self.destination_domain_id()?;
+
+        Ok(token_messenger.deposit_for_burn_with_caller_transaction(
+            from,
+            self.recipient,
+            destination_domain.as_u32(),
+            token_address,
+            amount,
+            destination_caller,
+        ))
+    }
+
+    /// Builds the `replaceDepositForBurn` sent by [`Self::replace_deposit_for_burn`]
+    pub(super) fn replace_deposit_for_burn_transaction_request(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_destination_caller: Address,
+        new_mint_recipient: Address,
+        from: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let token_messenger = TokenMessengerContract::new(
+            self.token_messenger_contract()?,
+            self.source_provider.clone(),
+        );
+
+        Ok(token_messenger.replace_deposit_for_burn_transaction(
+            from,
+            Bytes::from(original_message),
+            Bytes::from(original_attestation),
+            new_destination_caller,
+            new_mint_recipient,
+        ))
+    }
+
+    /// Builds the `replaceMessage` sent by [`Self::replace_message`]
+    pub(super) fn replace_message_transaction_request(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_message_body: Vec<u8>,
+        new_destination_caller: Address,
+        from: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let message_transmitter = MessageTransmitterContract::new(
+            self.source_config().message_transmitter_address()?,
+            self.source_provider.clone(),
+        );
+
+        Ok(message_transmitter.replace_message_transaction(
+            Bytes::from(original_message),
+            Bytes::from(original_attestation),
+            Bytes::from(new_message_body),
+            new_destination_caller,
+            from,
+        ))
+    }
+
     /// Constructs the Iris API URL for attestation polling
     ///
     /// The message hash is formatted with the `0x` prefix as required by Circle's API.
@@ -429,13 +726,46 @@ impl<P: Provider<Ethereum> + Clone> Cctp<P> {
 }
 
 // Implement CctpBridge trait for v1 Cctp struct
+impl<SP, DP, SN, DN> Cctp<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + WalletProvider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Take the source signer from the source provider's wallet
+    ///
+    /// Sets [`Self::source_signer`] to the wallet's default signer when none
+    /// is configured, and otherwise checks that the wallet can sign for the
+    /// configured one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if the wallet has no key for the
+    /// configured source signer.
+    pub fn with_source_wallet(mut self) -> Result<Self> {
+        self.source_signer = Some(wallet_signer(
+            "source",
+            &self.source_provider,
+            self.source_signer,
+        )?);
+        Ok(self)
+    }
+}
+
 #[async_trait]
-impl<P: Provider<Ethereum> + Clone> CctpBridge for Cctp<P> {
-    fn source_chain(&self) -> NamedChain {
+impl<SP, DP, SN, DN> CctpBridge for Cctp<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    fn source_chain(&self) -> Chain {
         self.source_chain
     }
 
-    fn destination_chain(&self) -> NamedChain {
+    fn destination_chain(&self) -> Chain {
         self.destination_chain
     }
 
@@ -463,6 +793,7 @@ impl<P: Provider<Ethereum> + Clone> CctpBridge for Cctp<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bridge::{IRIS_API, IRIS_API_SANDBOX};
     use alloy_chains::NamedChain;
     use alloy_primitives::{Address, FixedBytes};
     use alloy_provider::ProviderBuilder;
@@ -595,6 +926,93 @@ mod tests {
         insta::assert_snapshot!(url_mixed.as_str(), @"https://iris-api.circle.com/v1/attestations/0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
     }
 
+    #[test]
+    fn test_deposit_for_burn_with_caller_calldata() {
+        use crate::contracts::token_messenger::TokenMessenger::depositForBurnWithCallerCall;
+        use alloy_sol_types::SolCall;
+
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let token_messenger = TokenMessengerContract::new(
+            NamedChain::Mainnet.token_messenger_address().unwrap(),
+            provider,
+        );
+        let recipient = Address::repeat_byte(0x11);
+        let token = Address::repeat_byte(0x22);
+        let caller = Address::repeat_byte(0x33);
+
+        let tx = token_messenger.deposit_for_burn_with_caller_transaction(
+            Address::repeat_byte(0x44),
+            recipient,
+            DomainId::Arbitrum.as_u32(),
+            token,
+            U256::from(1_000_000),
+            caller,
+        );
+
+        let input = tx.input.input().unwrap();
+        let call = depositForBurnWithCallerCall::abi_decode(input).unwrap();
+        assert_eq!(call.amount, U256::from(1_000_000));
+        assert_eq!(call.destinationDomain, 3);
+        assert_eq!(call.mintRecipient, recipient.into_word());
+        assert_eq!(call.burnToken, token);
+        assert_eq!(call.destinationCaller, caller.into_word());
+    }
+
+    #[test]
+    fn test_replace_deposit_for_burn_calldata() {
+        use crate::contracts::token_messenger::TokenMessenger::replaceDepositForBurnCall;
+        use alloy_sol_types::SolCall;
+
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let token_messenger = TokenMessengerContract::new(
+            NamedChain::Mainnet.token_messenger_address().unwrap(),
+            provider,
+        );
+        let new_caller = Address::repeat_byte(0x33);
+        let new_recipient = Address::repeat_byte(0x55);
+
+        let tx = token_messenger.replace_deposit_for_burn_transaction(
+            Address::repeat_byte(0x44),
+            Bytes::from(vec![1, 2, 3]),
+            Bytes::from(vec![4, 5]),
+            new_caller,
+            new_recipient,
+        );
+
+        let call = replaceDepositForBurnCall::abi_decode(tx.input.input().unwrap()).unwrap();
+        assert_eq!(call.originalMessage, Bytes::from(vec![1, 2, 3]));
+        assert_eq!(call.originalAttestation, Bytes::from(vec![4, 5]));
+        assert_eq!(call.newDestinationCaller, new_caller.into_word());
+        assert_eq!(call.newMintRecipient, new_recipient.into_word());
+    }
+
+    #[test]
+    fn test_replace_message_calldata() {
+        use crate::contracts::message_transmitter::MessageTransmitter::replaceMessageCall;
+        use alloy_sol_types::SolCall;
+
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let message_transmitter = MessageTransmitterContract::new(
+            NamedChain::Mainnet.message_transmitter_address().unwrap(),
+            provider,
+        );
+
+        let tx = message_transmitter.replace_message_transaction(
+            Bytes::from(vec![1, 2, 3]),
+            Bytes::from(vec![4, 5]),
+            Bytes::from(vec![6]),
+            Address::ZERO,
+            Address::repeat_byte(0x44),
+        );
+
+        let call = replaceMessageCall::abi_decode(tx.input.input().unwrap()).unwrap();
+        assert_eq!(call.newMessageBody, Bytes::from(vec![6]));
+        assert_eq!(call.newDestinationCaller, FixedBytes::ZERO);
+    }
+
     #[test]
     fn test_attestation_url_uses_correct_environment() {
         let provider =
@@ -630,4 +1048,32 @@ mod tests {
             "Testnet should use sandbox Iris API"
         );
     }
+
+    #[test]
+    fn test_source_signer_from_provider_wallet() {
+        use alloy_signer_local::PrivateKeySigner;
+
+        let key = PrivateKeySigner::random();
+        let provider = ProviderBuilder::new()
+            .wallet(key.clone())
+            .connect_http("http://localhost:8545".parse().unwrap());
+        let builder = || {
+            Cctp::builder()
+                .source_chain(NamedChain::Mainnet)
+                .destination_chain(NamedChain::Arbitrum)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::ZERO)
+        };
+
+        let bridge = builder().build().with_source_wallet().unwrap();
+        assert_eq!(bridge.source_signer(), Some(key.address()));
+
+        let err = builder()
+            .source_signer(Address::repeat_byte(0x01))
+            .build()
+            .with_source_wallet()
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+    }
 }
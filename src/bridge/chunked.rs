@@ -0,0 +1,443 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transfers split across several burns to respect `burnLimitsPerMessage`.
+//!
+//! The `TokenMinterV2` caps how much a single `depositForBurn` may move.
+//! [`CctpV2::burn_chunked`] splits a larger amount into compliant burns and
+//! [`CctpV2::transfer_chunked`] carries every chunk through attestation and
+//! mint, reporting the result as one [`ChunkedTransfer`].
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{Address, TxHash, U256};
+use alloy_provider::Provider;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+
+use super::transfer::{default_polling_config, TransferState};
+use super::v2::{CctpV2, MintResult};
+use crate::error::{CctpError, Result};
+
+/// One burn of a [`ChunkedTransfer`] and how far it has progressed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferChunk {
+    /// Amount moved by this chunk (in atomic units)
+    pub amount: U256,
+    /// Progress of this chunk, from [`TransferState::Planned`] to
+    /// [`TransferState::Minted`] or [`TransferState::AlreadyRelayed`]
+    pub state: TransferState,
+}
+
+/// A logical transfer made of several burns, in nonce order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkedTransfer {
+    /// The chunks, in the order their burns were sent
+    pub chunks: Vec<TransferChunk>,
+}
+
+impl ChunkedTransfer {
+    /// Total amount across all chunks
+    pub fn total_amount(&self) -> U256 {
+        self.chunks.iter().map(|chunk| chunk.amount).sum()
+    }
+
+    /// Amount in chunks that were minted, by us or by a relayer
+    pub fn delivered_amount(&self) -> U256 {
+        self.chunks
+            .iter()
+            .filter(|chunk| chunk.state.is_complete())
+            .map(|chunk| chunk.amount)
+            .sum()
+    }
+
+    /// Returns true once every chunk has been minted
+    pub fn is_complete(&self) -> bool {
+        self.chunks.iter().all(|chunk| chunk.state.is_complete())
+    }
+
+    /// Returns true if any chunk failed; see each chunk's state for the reason
+    pub fn has_failures(&self) -> bool {
+        self.chunks.iter().any(|chunk| chunk.state.is_failed())
+    }
+
+    /// Burn transaction hashes of every chunk that was sent
+    pub fn burn_txs(&self) -> Vec<TxHash> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| chunk.state.burn_tx())
+            .collect()
+    }
+}
+
+/// Most burns a chunked transfer may be split into
+///
+/// A small burn limit, as set on a devnet or in a custom registry, would
+/// otherwise turn a large amount into millions of burns.
+pub const MAX_BURN_CHUNKS: usize = 256;
+
+/// Splits `amount` into chunks of at most `limit`, largest first.
+///
+/// # Errors
+///
+/// Returns [`CctpError::BurnLimitExceeded`] if `limit` is zero, meaning the
+/// token cannot be burned at all, and [`CctpError::InvalidConfig`] if more
+/// than [`MAX_BURN_CHUNKS`] chunks would be needed.
+pub(crate) fn split_amount(amount: U256, limit: U256) -> Result<Vec<U256>> {
+    if limit.is_zero() {
+        return Err(CctpError::BurnLimitExceeded { amount, limit });
+    }
+
+    let count = amount.div_ceil(limit);
+    if count > U256::from(MAX_BURN_CHUNKS) {
+        return Err(CctpError::InvalidConfig(format!(
+            "amount {amount} needs {count} burns at a limit of {limit}, more than {MAX_BURN_CHUNKS}"
+        )));
+    }
+
+    let mut chunks = vec![limit; (amount / limit).to::<usize>()];
+    let remainder = amount % limit;
+    if !remainder.is_zero() {
+        chunks.push(remainder);
+    }
+    Ok(chunks)
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Burn an amount that may exceed the per-message burn limit
+    ///
+    /// Splits `amount` by [`Self::burn_limit_per_message`] and sends one burn
+    /// per chunk with consecutive nonces, starting from `from`'s pending
+    /// nonce, or assigned by the [`nonce_manager`](Self::nonce_manager) when
+    /// one is set. If a burn fails to send, the later chunks stay
+    /// [`TransferState::Planned`] so no nonce gap is left behind. When
+    /// [`replacement_policy`](Self::replacement_policy) is set, stuck burns
+    /// are re-sent with bumped fees and each chunk records the included
+    /// hash. When [`confirmations`](Self::confirmations) is set, each burn
+    /// receipt is awaited before returning.
+    ///
+    /// Like [`Self::burn`], this does not approve; call
+    /// [`Self::ensure_approval`] for the full amount first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if nothing was burned, including when `amount`
+    /// needs more than [`MAX_BURN_CHUNKS`] burns. Once any burn is sent,
+    /// failures are recorded on the affected chunks instead.
+    pub async fn burn_chunked(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<ChunkedTransfer> {
+        let limit = self.burn_limit_per_message(token_address).await?;
+        let amounts = split_amount(amount, limit)?;
+        if amounts.is_empty() {
+            return Err(CctpError::InvalidConfig(
+                "chunked burn amount must be non-zero".to_string(),
+            ));
+        }
+        let first_nonce = match self.nonce_manager() {
+            Some(_) => None,
+            None => Some(
+                self.source_provider()
+                    .get_transaction_count(from)
+                    .pending()
+                    .await?,
+            ),
+        };
+
+        info!(
+            amount = %amount,
+            limit = %limit,
+            chunks = amounts.len(),
+            first_nonce = ?first_nonce,
+            version = "v2",
+            event = "chunked_burn_started"
+        );
+
+        let mut chunks = Vec::with_capacity(amounts.len());
+        let mut send_failed = false;
+        for (index, chunk_amount) in (0..).zip(amounts) {
+            let nonce = first_nonce.map(|first| first + index);
+            let state = if send_failed {
+                TransferState::Planned
+            } else {
+                match self
+                    .send_burn_with_nonce(chunk_amount, from, token_address, nonce)
+                    .await
+                {
+                    Ok(burn_tx) => TransferState::Burned {
+                        burn_tx,
+                        replacements: Vec::new(),
+                    },
+                    Err(e) if chunks.is_empty() => return Err(e),
+                    Err(e) => {
+                        error!(
+                            nonce = ?nonce,
+                            error = %e,
+                            version = "v2",
+                            event = "chunked_burn_send_failed"
+                        );
+                        send_failed = true;
+                        failed(TransferState::Planned, &e)
+                    }
+                }
+            };
+            chunks.push(TransferChunk {
+                amount: chunk_amount,
+                state,
+            });
+        }
+
+        if self.replacement_policy().is_some() {
+            for chunk in &mut chunks {
+                if let TransferState::Burned { burn_tx, .. } = chunk.state {
+                    chunk.state = match self.included_burn(burn_tx).await {
+                        Ok(burn_tx) => TransferState::Burned {
+                            burn_tx,
+                            replacements: Vec::new(),
+                        },
+                        Err(e) => failed(chunk.state.clone(), &e),
+                    };
+                }
+            }
+        }
+
+        if self.confirmations().is_some() {
+            for chunk in &mut chunks {
+                if let TransferState::Burned { burn_tx, .. } = chunk.state {
+                    if let Err(e) = self.wait_for_burn_receipt(burn_tx).await {
+                        chunk.state = failed(chunk.state.clone(), &e);
+                    }
+                }
+            }
+        }
+
+        Ok(ChunkedTransfer { chunks })
+    }
+
+    /// Transfer an amount that may exceed the per-message burn limit
+    ///
+    /// Burns with [`Self::burn_chunked`], then waits for each chunk's
+    /// attestation and mints it with [`Self::mint_if_needed`]. A chunk that
+    /// fails is marked [`TransferState::Failed`] and the others continue, so
+    /// the returned [`ChunkedTransfer`] always accounts for every burn.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// bridge.ensure_approval(usdc, from, amount).await?;
+    /// let transfer = bridge.transfer_chunked(amount, from, usdc).await?;
+    /// if !transfer.is_complete() {
+    ///     eprintln!("delivered {} of {}", transfer.delivered_amount(), transfer.total_amount());
+    /// }
+    /// ```
+    pub async fn transfer_chunked(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<ChunkedTransfer> {
+        let mut transfer = self.burn_chunked(amount, from, token_address).await?;
+        let polling_config = default_polling_config(self);
+
+        for chunk in &mut transfer.chunks {
+            let TransferState::Burned { burn_tx, .. } = chunk.state else {
+                continue;
+            };
+            chunk.state = match self.get_attestation(burn_tx, polling_config).await {
+                Ok((message, attestation)) => TransferState::Attested {
+                    burn_tx,
+                    message: message.into(),
+                    attestation: attestation.into(),
+                    mint_txs: Vec::new(),
+                },
+                Err(e) => failed(chunk.state.clone(), &e),
+            };
+        }
+
+        for chunk in &mut transfer.chunks {
+            let TransferState::Attested {
+                burn_tx,
+                ref message,
+                ref attestation,
+                ..
+            } = chunk.state
+            else {
+                continue;
+            };
+            chunk.state = match self
+                .mint_chunk(
+                    message.to_vec(),
+                    attestation.to_vec(),
+                    self.mint_sender(from),
+                )
+                .await
+            {
+                Ok(MintResult::Minted(mint_tx)) => TransferState::Minted { burn_tx, mint_tx },
+                Ok(MintResult::AlreadyRelayed) => TransferState::AlreadyRelayed { burn_tx },
+                Err(e) => failed(chunk.state.clone(), &e),
+            };
+        }
+
+        info!(
+            total_amount = %transfer.total_amount(),
+            delivered_amount = %transfer.delivered_amount(),
+            chunks = transfer.chunks.len(),
+            complete = transfer.is_complete(),
+            version = "v2",
+            event = "chunked_transfer_finished"
+        );
+
+        Ok(transfer)
+    }
+
+    async fn send_burn_with_nonce(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        nonce: Option<u64>,
+    ) -> Result<TxHash> {
+        self.check_source_sender(from)?;
+        let mut tx_request = self.burn_transaction_request(amount, from, token_address)?;
+        self.default_tx_options()
+            .apply(self.source_provider(), &mut tx_request)
+            .await?;
+        if let Some(nonce) = nonce {
+            tx_request.set_nonce(nonce);
+        }
+
+        let pending_tx = self.send_source(from, tx_request).await?;
+        let tx_hash = *pending_tx.tx_hash();
+
+        info!(
+            tx_hash = %tx_hash,
+            amount = %amount,
+            nonce = ?nonce,
+            version = "v2",
+            event = "chunk_burn_transaction_sent"
+        );
+
+        Ok(tx_hash)
+    }
+
+    async fn mint_chunk(
+        &self,
+        message: Vec<u8>,
+        attestation: Vec<u8>,
+        from: Address,
+    ) -> Result<MintResult> {
+        let result = match self.mint_if_needed(message, attestation, from).await? {
+            MintResult::Minted(mint_tx) => MintResult::Minted(self.included_mint(mint_tx).await?),
+            MintResult::AlreadyRelayed => MintResult::AlreadyRelayed,
+        };
+        if let (MintResult::Minted(mint_tx), Some(_)) = (&result, self.confirmations()) {
+            self.wait_for_mint_receipt(*mint_tx).await?;
+        }
+        Ok(result)
+    }
+}
+
+fn failed(previous: TransferState, error: &CctpError) -> TransferState {
+    warn!(
+        previous = ?previous,
+        error = %error,
+        version = "v2",
+        event = "transfer_chunk_failed"
+    );
+    TransferState::Failed {
+        previous: Box::new(previous),
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(10, 4, vec![4, 4, 2])]
+    #[case(8, 4, vec![4, 4])]
+    #[case(3, 4, vec![3])]
+    #[case(0, 4, vec![])]
+    fn test_split_amount(#[case] amount: u64, #[case] limit: u64, #[case] expected: Vec<u64>) {
+        let chunks = split_amount(U256::from(amount), U256::from(limit)).unwrap();
+        let expected: Vec<U256> = expected.into_iter().map(U256::from).collect();
+        assert_eq!(chunks, expected);
+        assert_eq!(chunks.iter().copied().sum::<U256>(), U256::from(amount));
+    }
+
+    #[test]
+    fn test_split_amount_rejects_zero_limit() {
+        let err = split_amount(U256::from(10), U256::ZERO).unwrap_err();
+        assert!(matches!(err, CctpError::BurnLimitExceeded { .. }));
+    }
+
+    #[test]
+    fn test_split_amount_rejects_too_many_chunks() {
+        let max = U256::from(MAX_BURN_CHUNKS);
+        assert_eq!(
+            split_amount(max, U256::from(1)).unwrap().len(),
+            MAX_BURN_CHUNKS
+        );
+
+        let err = split_amount(max + U256::from(1), U256::from(1)).unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+        let err = split_amount(U256::MAX, U256::from(1)).unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn test_chunked_transfer_aggregates() {
+        let burn_tx = TxHash::repeat_byte(1);
+        let transfer = ChunkedTransfer {
+            chunks: vec![
+                TransferChunk {
+                    amount: U256::from(4),
+                    state: TransferState::Minted {
+                        burn_tx,
+                        mint_tx: TxHash::repeat_byte(2),
+                    },
+                },
+                TransferChunk {
+                    amount: U256::from(4),
+                    state: TransferState::AlreadyRelayed {
+                        burn_tx: TxHash::repeat_byte(3),
+                    },
+                },
+                TransferChunk {
+                    amount: U256::from(2),
+                    state: TransferState::Failed {
+                        previous: Box::new(TransferState::Burned {
+                            burn_tx: TxHash::repeat_byte(4),
+                            replacements: Vec::new(),
+                        }),
+                        reason: "Timeout waiting for attestation".to_string(),
+                    },
+                },
+                TransferChunk {
+                    amount: U256::from(1),
+                    state: TransferState::Planned,
+                },
+            ],
+        };
+
+        assert_eq!(transfer.total_amount(), U256::from(11));
+        assert_eq!(transfer.delivered_amount(), U256::from(8));
+        assert!(!transfer.is_complete());
+        assert!(transfer.has_failures());
+        assert_eq!(
+            transfer.burn_txs(),
+            vec![burn_tx, TxHash::repeat_byte(3), TxHash::repeat_byte(4)]
+        );
+    }
+}
@@ -21,6 +21,9 @@ pub const ATTESTATION_PATH_V1: &str = "/v1/attestations/";
 /// - V2: `/v2/messages/{sourceDomain}?transactionHash={txHash}`
 pub const MESSAGES_PATH_V2: &str = "/v2/messages/";
 
+/// CCTP v2 USDC transfer fees API path: `/v2/burn/USDC/fees/{sourceDomain}/{destDomain}`
+pub const FEES_PATH_V2: &str = "/v2/burn/USDC/fees/";
+
 /// Configuration for attestation polling behavior.
 ///
 /// Controls how the bridge polls Circle's Iris API for attestation availability.
@@ -8,12 +8,38 @@
 
 mod bridge_trait;
 mod cctp;
+mod chunked;
 mod config;
 pub mod multicall;
+mod nonce;
+mod receipt;
+mod replacement;
+mod safe;
+mod simulation;
+mod transfer;
+mod tx_options;
+mod unsigned;
+mod user_operation;
 mod v2;
+mod verify;
 
 pub use bridge_trait::CctpBridge;
 pub use cctp::Cctp;
+pub use chunked::{ChunkedTransfer, TransferChunk, MAX_BURN_CHUNKS};
 pub use config::PollingConfig;
+pub(crate) use config::{FEES_PATH_V2, IRIS_API, IRIS_API_SANDBOX};
 pub use multicall::{batch_token_state, TokenState};
-pub use v2::{CctpV2, MintResult};
+pub use nonce::NonceManager;
+pub use receipt::ReceiptSummary;
+pub use replacement::ReplacementPolicy;
+pub use safe::{
+    encode_multi_send, SafeBatch, SafeBatchMeta, SafeTransaction, MULTI_SEND_CALL_ONLY,
+};
+pub use transfer::{InMemoryTransferStore, Transfer, TransferRecord, TransferState, TransferStore};
+pub use tx_options::{BufferedFees, FeeStrategy, FixedFees, PercentileFees, TxOptions};
+pub use user_operation::{
+    simple_account_execute_batch, Bundler, HttpBundler, PackedUserOperation, UserOperation,
+    ENTRY_POINT_V07,
+};
+pub use v2::{CctpV2, MintResult, PERMIT_BURN_GAS_LIMIT};
+pub use verify::{verify_chain_config, verify_chain_record, ChainConfigReport, ConfigMismatch};
@@ -32,7 +32,7 @@
 
 use crate::contracts::erc20::Erc20Contract;
 use crate::error::Result;
-use alloy_network::Ethereum;
+use alloy_network::Network;
 use alloy_primitives::{Address, U256};
 use alloy_provider::Provider;
 
@@ -92,14 +92,15 @@ impl TokenState {
 /// // Now safe to burn
 /// bridge.burn(amount, sender, usdc).await?;
 /// ```
-pub async fn batch_token_state<P>(
+pub async fn batch_token_state<P, N>(
     provider: &P,
     token: Address,
     owner: Address,
     spender: Address,
 ) -> Result<TokenState>
 where
-    P: Provider<Ethereum> + Clone,
+    P: Provider<N> + Clone,
+    N: Network,
 {
     let erc20 = Erc20Contract::new(token, provider.clone());
 
@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-sender nonce assignment for concurrent sends.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{Address, ChainId};
+use alloy_provider::{PendingTransactionBuilder, Provider};
+use tracing::{debug, warn};
+
+use crate::error::Result;
+
+/// Next nonce of one sender on one chain; `None` until synced with the node.
+type NonceSlot = Arc<tokio::sync::Mutex<Option<u64>>>;
+
+/// Assigns consecutive nonces to transactions sent concurrently by one
+/// account.
+///
+/// The provider's nonce filler asks the node for the pending nonce on every
+/// send. Firing several burns at once from one account races on that
+/// lookup, which yields "nonce too low" errors, or gaps once one of them
+/// fails. A [`NonceManager`] set on the bridge builder assigns nonces itself
+/// instead. Each (chain, sender) pair gets a lock held from nonce lookup
+/// until the node accepts the transaction, so concurrent burns, approvals
+/// and mints from one account are sent with consecutive nonces.
+///
+/// The first send, and the first send after any failed one, re-syncs with
+/// the node's pending nonce. Call [`NonceManager::resync`] after a sent
+/// transaction is dropped from the mempool.
+///
+/// Clones share state, so one manager can be given to several bridges that
+/// send from the same accounts.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let bridge = CctpV2Bridge::builder()
+///     // ...
+///     .nonce_manager(NonceManager::new())
+///     .build();
+///
+/// let burns = payouts
+///     .iter()
+///     .map(|amount| bridge.burn(*amount, treasury, usdc));
+/// let burn_txs = futures::future::try_join_all(burns).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NonceManager {
+    slots: Arc<Mutex<HashMap<(ChainId, Address), NonceSlot>>>,
+}
+
+impl NonceManager {
+    /// Create a manager with no cached nonces
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The nonce the next send from `from` on `chain_id` will use, if synced
+    pub async fn next_nonce(&self, chain_id: ChainId, from: Address) -> Option<u64> {
+        *self.slot(chain_id, from).lock().await
+    }
+
+    /// Forget the cached nonce, so the next send re-reads the pending nonce
+    ///
+    /// Waits for any send from `from` on `chain_id` that is in flight.
+    pub async fn resync(&self, chain_id: ChainId, from: Address) {
+        *self.slot(chain_id, from).lock().await = None;
+    }
+
+    fn slot(&self, chain_id: ChainId, from: Address) -> NonceSlot {
+        self.slots
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry((chain_id, from))
+            .or_default()
+            .clone()
+    }
+
+    /// Send `tx_request` from `from`, assigning it the next nonce
+    ///
+    /// A nonce already set on the request is kept, and the cached nonce
+    /// moves past it. Any send error clears the cache.
+    pub(crate) async fn send<P: Provider<N>, N: Network>(
+        &self,
+        provider: &P,
+        chain_id: ChainId,
+        from: Address,
+        mut tx_request: N::TransactionRequest,
+    ) -> Result<PendingTransactionBuilder<N>> {
+        let slot = self.slot(chain_id, from);
+        let mut next = slot.lock().await;
+
+        let nonce = match (tx_request.nonce(), *next) {
+            (Some(nonce), _) => nonce,
+            (None, Some(nonce)) => nonce,
+            (None, None) => provider.get_transaction_count(from).pending().await?,
+        };
+        tx_request.set_nonce(nonce);
+
+        match provider.send_transaction(tx_request).await {
+            Ok(pending_tx) => {
+                *next = Some(next.map_or(nonce + 1, |cached| cached.max(nonce + 1)));
+                debug!(
+                    chain_id = chain_id,
+                    from = %from,
+                    nonce = nonce,
+                    event = "nonce_assigned"
+                );
+                Ok(pending_tx)
+            }
+            Err(e) => {
+                *next = None;
+                warn!(
+                    chain_id = chain_id,
+                    from = %from,
+                    nonce = nonce,
+                    error = %e,
+                    event = "nonce_resync_scheduled"
+                );
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mocked;
+    use alloy_network::Ethereum;
+    use alloy_primitives::{TxHash, U64};
+    use alloy_rpc_types::TransactionRequest;
+
+    const FROM: Address = Address::repeat_byte(0x01);
+
+    fn request() -> TransactionRequest {
+        TransactionRequest::default().from(FROM)
+    }
+
+    async fn send(
+        manager: &NonceManager,
+        provider: &impl Provider<Ethereum>,
+        tx_request: TransactionRequest,
+    ) -> Result<TxHash> {
+        let pending_tx = manager.send(provider, 1, FROM, tx_request).await?;
+        Ok(*pending_tx.tx_hash())
+    }
+
+    #[tokio::test]
+    async fn test_syncs_once_then_counts_up() {
+        let (asserter, provider) = mocked();
+        let manager = NonceManager::new();
+        asserter.push_success(&U64::from(7));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+        asserter.push_success(&TxHash::repeat_byte(0xa2));
+
+        let (first, second) = tokio::join!(
+            manager.send(&provider, 1, FROM, request()),
+            manager.send(&provider, 1, FROM, request()),
+        );
+        assert_eq!(*first.unwrap().tx_hash(), TxHash::repeat_byte(0xa1));
+        assert_eq!(*second.unwrap().tx_hash(), TxHash::repeat_byte(0xa2));
+
+        assert_eq!(manager.next_nonce(1, FROM).await, Some(9));
+        assert_eq!(manager.next_nonce(8453, FROM).await, None);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_send_resyncs_with_pending_nonce() {
+        let (asserter, provider) = mocked();
+        let manager = NonceManager::new();
+        asserter.push_success(&U64::from(3));
+        asserter.push_failure_msg("nonce too low");
+        asserter.push_success(&U64::from(5));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+
+        manager
+            .send(&provider, 1, FROM, request())
+            .await
+            .unwrap_err();
+        assert_eq!(manager.next_nonce(1, FROM).await, None);
+
+        send(&manager, &provider, request()).await.unwrap();
+        assert_eq!(manager.next_nonce(1, FROM).await, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_explicit_nonce_moves_cache_forward() {
+        let (asserter, provider) = mocked();
+        let manager = NonceManager::new();
+        asserter.push_success(&U64::from(2));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+        asserter.push_success(&TxHash::repeat_byte(0xa2));
+
+        send(&manager, &provider, request()).await.unwrap();
+        send(&manager, &provider, request().nonce(10))
+            .await
+            .unwrap();
+        assert_eq!(manager.next_nonce(1, FROM).await, Some(11));
+
+        manager.resync(1, FROM).await;
+        assert_eq!(manager.next_nonce(1, FROM).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_state() {
+        let (asserter, provider) = mocked();
+        let manager = NonceManager::new();
+        asserter.push_success(&U64::from(4));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+
+        send(&manager.clone(), &provider, request()).await.unwrap();
+        assert_eq!(manager.next_nonce(1, FROM).await, Some(5));
+    }
+}
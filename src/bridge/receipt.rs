@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Receipt confirmation and revert detection for bridge transactions.
+
+use crate::error::{decode_revert_reason, CctpError, Result, RevertedTransaction};
+use alloy_network::{Network, ReceiptResponse};
+use alloy_primitives::{Bytes, TxHash};
+use alloy_provider::{PendingTransactionBuilder, Provider};
+use alloy_rpc_types::{BlockId, Log};
+use alloy_transport::TransportResult;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+/// Key facts from a mined, successful bridge transaction.
+///
+/// Returned by the `*_and_confirm` methods on [`CctpV2`](crate::CctpV2Bridge)
+/// once the transaction has reached the configured confirmation depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptSummary {
+    /// Hash of the confirmed transaction.
+    pub tx_hash: TxHash,
+    /// Block the transaction was included in.
+    pub block_number: Option<u64>,
+    /// Gas consumed by the transaction.
+    pub gas_used: u64,
+    /// Price per unit of gas actually paid, in wei.
+    pub effective_gas_price: u128,
+}
+
+impl<R: ReceiptResponse> From<&R> for ReceiptSummary {
+    fn from(receipt: &R) -> Self {
+        Self {
+            tx_hash: receipt.transaction_hash(),
+            block_number: receipt.block_number(),
+            gas_used: receipt.gas_used(),
+            effective_gas_price: receipt.effective_gas_price(),
+        }
+    }
+}
+
+/// Which bridge step a confirmed transaction belongs to.
+///
+/// Selects the [`CctpError`] variant returned when the transaction reverts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TxKind {
+    Approval,
+    Burn,
+    Mint,
+}
+
+impl TxKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Approval => "approval",
+            Self::Burn => "burn",
+            Self::Mint => "mint",
+        }
+    }
+
+    fn reverted(self, revert: RevertedTransaction) -> CctpError {
+        match self {
+            Self::Approval => CctpError::ApprovalReverted(revert),
+            Self::Burn => CctpError::BurnReverted(revert),
+            Self::Mint => CctpError::MintReverted(revert),
+        }
+    }
+}
+
+/// Waits for `tx_hash` to reach `confirmations` blocks and checks its status.
+///
+/// See [`confirm_receipt`] for how reverts are reported.
+pub(crate) async fn confirm_transaction<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_hash: TxHash,
+    confirmations: u64,
+    kind: TxKind,
+) -> Result<ReceiptSummary> {
+    let receipt = confirm_receipt(provider, tx_hash, confirmations, kind).await?;
+    Ok(ReceiptSummary::from(&receipt))
+}
+
+/// Like [`confirm_transaction`], but returns the network's full receipt.
+///
+/// On a reverted receipt the original transaction is replayed with `eth_call`
+/// against the parent block to recover the revert data, which is attached to
+/// the returned error alongside the decoded reason when it is an
+/// `Error(string)` or `Panic(uint256)`.
+pub(crate) async fn confirm_receipt<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_hash: TxHash,
+    confirmations: u64,
+    kind: TxKind,
+) -> Result<N::ReceiptResponse> {
+    debug!(
+        tx_hash = %tx_hash,
+        confirmations = confirmations,
+        kind = kind.as_str(),
+        event = "waiting_for_receipt"
+    );
+
+    let receipt = PendingTransactionBuilder::new(provider.root().clone(), tx_hash)
+        .with_required_confirmations(confirmations)
+        .get_receipt()
+        .await?;
+
+    let summary = ReceiptSummary::from(&receipt);
+
+    if receipt.status() {
+        info!(
+            tx_hash = %tx_hash,
+            block_number = ?summary.block_number,
+            gas_used = summary.gas_used,
+            kind = kind.as_str(),
+            event = "transaction_confirmed"
+        );
+        return Ok(receipt);
+    }
+
+    let revert_data = replay_revert_data(provider, tx_hash, summary.block_number).await;
+    let reason = revert_data
+        .as_ref()
+        .and_then(|data| decode_revert_reason(data));
+
+    warn!(
+        tx_hash = %tx_hash,
+        block_number = ?summary.block_number,
+        gas_used = summary.gas_used,
+        reason = reason.as_deref().unwrap_or("unknown"),
+        kind = kind.as_str(),
+        event = "transaction_reverted"
+    );
+
+    Err(kind.reverted(RevertedTransaction {
+        tx_hash,
+        block_number: summary.block_number,
+        gas_used: summary.gas_used,
+        revert_data,
+        reason,
+    }))
+}
+
+/// Re-executes a mined transaction to recover its revert data.
+///
+/// This is best-effort: the replay runs against the parent block, so it can
+/// miss reverts that depend on earlier transactions in the same block.
+async fn replay_revert_data<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_hash: TxHash,
+    block_number: Option<u64>,
+) -> Option<Bytes> {
+    let tx = provider
+        .get_transaction_by_hash(tx_hash)
+        .await
+        .ok()
+        .flatten()?;
+    let block = BlockId::number(block_number?.saturating_sub(1));
+
+    match provider
+        .call(N::TransactionRequest::from(tx))
+        .block(block)
+        .await
+    {
+        Ok(_) => None,
+        Err(e) => e
+            .as_error_resp()
+            .and_then(|payload| payload.as_revert_data()),
+    }
+}
+
+/// The logs of a transaction's receipt, or `None` if it is not mined yet.
+///
+/// Reads only the `logs` field of `eth_getTransactionReceipt`, which every
+/// network's receipt carries, so this works for any [`Network`].
+pub(crate) async fn transaction_logs<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_hash: TxHash,
+) -> TransportResult<Option<Vec<Log>>> {
+    #[derive(Debug, Deserialize)]
+    struct ReceiptLogs {
+        logs: Vec<Log>,
+    }
+
+    let receipt: Option<ReceiptLogs> = provider
+        .raw_request("eth_getTransactionReceipt".into(), (tx_hash,))
+        .await?;
+    Ok(receipt.map(|receipt| receipt.logs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tx_kind_selects_error_variant() {
+        let revert = RevertedTransaction {
+            tx_hash: TxHash::ZERO,
+            block_number: Some(1),
+            gas_used: 21_000,
+            revert_data: None,
+            reason: None,
+        };
+
+        assert!(matches!(
+            TxKind::Approval.reverted(revert.clone()),
+            CctpError::ApprovalReverted(_)
+        ));
+        assert!(matches!(
+            TxKind::Burn.reverted(revert.clone()),
+            CctpError::BurnReverted(_)
+        ));
+        assert!(matches!(
+            TxKind::Mint.reverted(revert),
+            CctpError::MintReverted(_)
+        ));
+    }
+
+    #[test]
+    fn test_receipt_summary_serde_roundtrip() {
+        let summary = ReceiptSummary {
+            tx_hash: TxHash::repeat_byte(0xab),
+            block_number: Some(19_000_000),
+            gas_used: 120_000,
+            effective_gas_price: 30_000_000_000,
+        };
+
+        let json = serde_json::to_string(&summary).unwrap();
+        let decoded: ReceiptSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, summary);
+    }
+}
@@ -0,0 +1,733 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fee-bumping replacement of stuck burns and mints.
+//!
+//! When fees spike, a burn or mint can sit in the mempool long after it was
+//! sent. [`CctpV2::replace_stuck_burn`] and
+//! [`CctpV2::replace_stuck_mint`] watch such a transaction and, every
+//! [`ReplacementPolicy::interval_secs`], re-send it with the same nonce and
+//! higher fees until the original or one of its replacements is mined, or
+//! [`ReplacementPolicy::max_rounds`] intervals have passed.
+//! They return the hash that was actually included, which is the one Iris
+//! indexes the burn under.
+//!
+//! Set the bridge builder's `replacement_policy` to do this automatically in
+//! [`CctpV2::transfer`], [`Transfer`](super::Transfer) and
+//! [`CctpV2::transfer_chunked`]. Their results then carry the included
+//! hashes.
+//!
+//! Replacements are signed by the bridge's provider, so it must hold the key
+//! of the original sender.
+
+use std::future::{ready, Future, Ready};
+use std::time::Duration;
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::TxHash;
+use alloy_provider::Provider;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+use tracing::{debug, info, warn};
+
+use super::receipt::{transaction_logs, TxKind};
+use super::v2::CctpV2;
+use crate::error::{CctpError, Result};
+
+/// When and how far to bump the fees of a stuck transaction.
+///
+/// # Examples
+///
+/// ```rust
+/// use cctp_rs::ReplacementPolicy;
+///
+/// // Bump every 2 minutes, up to 5x the original fees, never above 500 gwei,
+/// // and give up after an hour
+/// let policy = ReplacementPolicy::default()
+///     .with_interval_secs(120)
+///     .with_max_multiplier_percent(500)
+///     .with_fee_cap(500_000_000_000)
+///     .with_max_rounds(30);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplacementPolicy {
+    /// Seconds to wait for inclusion before each bump.
+    pub interval_secs: u64,
+    /// Percentage added to the fees on each bump.
+    ///
+    /// Nodes reject replacements that raise fees by less than 10%.
+    pub bump_percent: u64,
+    /// Highest fee allowed, as a percentage of the original transaction's fee.
+    pub max_multiplier_percent: u64,
+    /// Highest max fee per gas (or gas price) allowed, in wei.
+    pub fee_cap: Option<u128>,
+    /// Intervals to wait for inclusion before giving up, if limited.
+    ///
+    /// Without a limit, a transaction dropped from the mempool once the
+    /// fees stop rising is watched forever.
+    pub max_rounds: Option<u32>,
+}
+
+impl Default for ReplacementPolicy {
+    /// Bumps by 15% every 60 seconds, up to 3x the original fees, uncapped,
+    /// and gives up after 30 minutes.
+    fn default() -> Self {
+        Self {
+            interval_secs: 60,
+            bump_percent: 15,
+            max_multiplier_percent: 300,
+            fee_cap: None,
+            max_rounds: Some(30),
+        }
+    }
+}
+
+impl ReplacementPolicy {
+    /// Sets the seconds to wait before each bump.
+    pub fn with_interval_secs(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    /// Sets the percentage added to the fees on each bump.
+    pub fn with_bump_percent(mut self, bump_percent: u64) -> Self {
+        self.bump_percent = bump_percent;
+        self
+    }
+
+    /// Sets the highest fee allowed, as a percentage of the original fee.
+    pub fn with_max_multiplier_percent(mut self, max_multiplier_percent: u64) -> Self {
+        self.max_multiplier_percent = max_multiplier_percent;
+        self
+    }
+
+    /// Sets the highest max fee per gas allowed, in wei.
+    pub fn with_fee_cap(mut self, fee_cap: u128) -> Self {
+        self.fee_cap = Some(fee_cap);
+        self
+    }
+
+    /// Sets the intervals to wait for inclusion before giving up.
+    pub fn with_max_rounds(mut self, max_rounds: u32) -> Self {
+        self.max_rounds = Some(max_rounds);
+        self
+    }
+
+    /// Returns the interval between bumps as a [`Duration`].
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+
+    /// Highest fee allowed for a transaction first sent with `original`.
+    fn limit(&self, original: u128) -> u128 {
+        let limit = original.saturating_mul(self.max_multiplier_percent as u128) / 100;
+        self.fee_cap.map_or(limit, |cap| limit.min(cap))
+    }
+
+    /// The next fee after `current`, or `None` once it would exceed `limit`.
+    fn bumped(&self, current: u128, limit: u128) -> Option<u128> {
+        let bumped = (current.saturating_mul(100 + self.bump_percent as u128))
+            .div_ceil(100)
+            .max(current.saturating_add(1));
+        (bumped <= limit).then_some(bumped)
+    }
+
+    /// Raises the fees of `current` by one step, relative to `original`.
+    ///
+    /// The max fee per gas (or legacy gas price) is limited by
+    /// [`Self::max_multiplier_percent`] and [`Self::fee_cap`]; the priority
+    /// fee only by the bumped max fee, so a zero tip can still be raised.
+    /// Returns false, leaving `current` untouched, once either fee has
+    /// reached its limit.
+    fn bump_fees<T: TransactionBuilder>(&self, original: &T, current: &mut T) -> bool {
+        if let (Some(original_max_fee), Some(max_fee)) =
+            (original.max_fee_per_gas(), current.max_fee_per_gas())
+        {
+            let priority_fee = current.max_priority_fee_per_gas().unwrap_or_default();
+            let Some(max_fee) = self.bumped(max_fee, self.limit(original_max_fee)) else {
+                return false;
+            };
+            let Some(priority_fee) = self.bumped(priority_fee, max_fee) else {
+                return false;
+            };
+            current.set_max_fee_per_gas(max_fee);
+            current.set_max_priority_fee_per_gas(priority_fee);
+            return true;
+        }
+
+        match (original.gas_price(), current.gas_price()) {
+            (Some(original_gas_price), Some(gas_price)) => {
+                match self.bumped(gas_price, self.limit(original_gas_price)) {
+                    Some(gas_price) => {
+                        current.set_gas_price(gas_price);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Re-sends `tx_hash` with bumped fees under `policy` until it or one of its
+/// replacements is mined, and returns the hash that was.
+///
+/// Inclusion is checked before each wait, so a transaction that is already
+/// mined returns at once.
+///
+/// # Errors
+///
+/// Returns [`CctpError::TransactionNotFound`] if `tx_hash` is unknown to the
+/// node, [`CctpError::NonceConsumed`] if its nonce was used by a
+/// transaction this watcher did not send, and
+/// [`CctpError::ReplacementRoundsExhausted`] once
+/// [`ReplacementPolicy::max_rounds`] intervals pass without inclusion.
+pub(crate) async fn replace_until_included<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_hash: TxHash,
+    policy: &ReplacementPolicy,
+    kind: TxKind,
+) -> Result<TxHash> {
+    replace_until_included_tracked(provider, vec![tx_hash], policy, kind, untracked).await
+}
+
+/// [`replace_until_included`] for a transaction already sent as each of
+/// `sent`, oldest first, calling `on_sent` with every hash sent so far after
+/// each new replacement.
+///
+/// Persist what `on_sent` receives and pass it back here to resume watching
+/// after a restart. Fees are bumped from the newest of `sent` the node still
+/// knows, and the fee limits count from it.
+pub(crate) async fn replace_until_included_tracked<P, N, F, Fut>(
+    provider: &P,
+    mut sent: Vec<TxHash>,
+    policy: &ReplacementPolicy,
+    kind: TxKind,
+    mut on_sent: F,
+) -> Result<TxHash>
+where
+    P: Provider<N>,
+    N: Network,
+    F: FnMut(&[TxHash]) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let (Some(&tx_hash), Some(&newest)) = (sent.first(), sent.last()) else {
+        return Err(CctpError::InvalidConfig(
+            "no transaction to watch for replacement".to_string(),
+        ));
+    };
+    let mut tx = None;
+    for known in sent.iter().rev() {
+        tx = provider.get_transaction_by_hash(*known).await?;
+        if tx.is_some() {
+            break;
+        }
+    }
+    let original: N::TransactionRequest = tx
+        .ok_or(CctpError::TransactionNotFound { tx_hash: newest })?
+        .into();
+    let (Some(from), Some(nonce)) = (original.from(), original.nonce()) else {
+        return Err(CctpError::TransactionNotFound { tx_hash: newest });
+    };
+
+    let mut current = original.clone();
+    let mut rounds = 0;
+    loop {
+        if let Some(included) = first_included(provider, &sent).await? {
+            info!(
+                kind = kind.as_str(),
+                tx_hash = %included,
+                original_tx_hash = %tx_hash,
+                replacements = sent.len() - 1,
+                event = "transaction_included"
+            );
+            return Ok(included);
+        }
+
+        if provider.get_transaction_count(from).latest().await? > nonce {
+            // One of ours may have been mined between the two checks
+            return match first_included(provider, &sent).await? {
+                Some(included) => Ok(included),
+                None => Err(CctpError::NonceConsumed { from, nonce }),
+            };
+        }
+
+        if policy
+            .max_rounds
+            .is_some_and(|max_rounds| rounds >= max_rounds)
+        {
+            warn!(
+                kind = kind.as_str(),
+                original_tx_hash = %tx_hash,
+                nonce = nonce,
+                rounds = rounds,
+                event = "replacement_rounds_exhausted"
+            );
+            return Err(CctpError::ReplacementRoundsExhausted {
+                tx_hash: sent[sent.len() - 1],
+                rounds,
+            });
+        }
+        // Every round after the first follows a full interval without inclusion
+        if rounds > 0 {
+            if policy.bump_fees(&original, &mut current) {
+                match provider.send_transaction(current.clone()).await {
+                    Ok(pending_tx) => {
+                        let replacement = *pending_tx.tx_hash();
+                        sent.push(replacement);
+                        on_sent(&sent).await?;
+                        info!(
+                            kind = kind.as_str(),
+                            tx_hash = %replacement,
+                            original_tx_hash = %tx_hash,
+                            nonce = nonce,
+                            max_fee_per_gas = ?current.max_fee_per_gas(),
+                            max_priority_fee_per_gas = ?current.max_priority_fee_per_gas(),
+                            gas_price = ?current.gas_price(),
+                            event = "replacement_transaction_sent"
+                        );
+                    }
+                    // Typically "nonce too low" once something was mined, which
+                    // the next round resolves.
+                    Err(e) => warn!(
+                        kind = kind.as_str(),
+                        original_tx_hash = %tx_hash,
+                        nonce = nonce,
+                        error = %e,
+                        event = "replacement_transaction_failed"
+                    ),
+                }
+            } else {
+                debug!(
+                    kind = kind.as_str(),
+                    tx_hash = %tx_hash,
+                    nonce = nonce,
+                    event = "replacement_fee_limit_reached"
+                );
+            }
+        }
+
+        sleep(policy.interval()).await;
+        rounds += 1;
+    }
+}
+
+/// An `on_sent` callback that keeps nothing.
+fn untracked(_sent: &[TxHash]) -> Ready<Result<()>> {
+    ready(Ok(()))
+}
+
+/// The most recently sent of `sent` that has a receipt, if any.
+async fn first_included<P: Provider<N>, N: Network>(
+    provider: &P,
+    sent: &[TxHash],
+) -> Result<Option<TxHash>> {
+    for tx_hash in sent.iter().rev() {
+        if transaction_logs(provider, *tx_hash).await?.is_some() {
+            return Ok(Some(*tx_hash));
+        }
+    }
+    Ok(None)
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Bump the fees of a pending burn until it is mined
+    ///
+    /// Returns the hash that was included: `burn_tx` itself or one of its
+    /// replacements. Use that hash for [`Self::get_attestation`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::NonceConsumed`] if the burn's nonce was used by
+    /// a transaction the watcher did not send.
+    pub async fn replace_stuck_burn(
+        &self,
+        burn_tx: TxHash,
+        policy: &ReplacementPolicy,
+    ) -> Result<TxHash> {
+        replace_until_included(self.source_provider(), burn_tx, policy, TxKind::Burn).await
+    }
+
+    /// Bump the fees of a pending mint until it is mined
+    ///
+    /// Returns the hash that was included: `mint_tx` itself or one of its
+    /// replacements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::NonceConsumed`] if the mint's nonce was used by
+    /// a transaction the watcher did not send.
+    pub async fn replace_stuck_mint(
+        &self,
+        mint_tx: TxHash,
+        policy: &ReplacementPolicy,
+    ) -> Result<TxHash> {
+        replace_until_included(self.destination_provider(), mint_tx, policy, TxKind::Mint).await
+    }
+
+    /// The included burn hash under the builder's replacement policy, or
+    /// `burn_tx` unchanged without one
+    pub(super) async fn included_burn(&self, burn_tx: TxHash) -> Result<TxHash> {
+        self.included_burn_tracked(vec![burn_tx], untracked).await
+    }
+
+    /// [`Self::included_burn`] for a burn sent as each of `sent`, oldest
+    /// first, reporting every replacement to `on_sent`
+    ///
+    /// Without a replacement policy, the newest of `sent` is returned.
+    pub(super) async fn included_burn_tracked<F, Fut>(
+        &self,
+        sent: Vec<TxHash>,
+        on_sent: F,
+    ) -> Result<TxHash>
+    where
+        F: FnMut(&[TxHash]) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        match self.replacement_policy() {
+            Some(policy) => {
+                replace_until_included_tracked(
+                    self.source_provider(),
+                    sent,
+                    &policy,
+                    TxKind::Burn,
+                    on_sent,
+                )
+                .await
+            }
+            None => newest(&sent),
+        }
+    }
+
+    /// The included mint hash under the builder's replacement policy, or
+    /// `mint_tx` unchanged without one
+    pub(super) async fn included_mint(&self, mint_tx: TxHash) -> Result<TxHash> {
+        self.included_mint_tracked(vec![mint_tx], untracked).await
+    }
+
+    /// [`Self::included_mint`] for a mint sent as each of `sent`, oldest
+    /// first, reporting every replacement to `on_sent`
+    ///
+    /// Without a replacement policy, the newest of `sent` is returned.
+    pub(super) async fn included_mint_tracked<F, Fut>(
+        &self,
+        sent: Vec<TxHash>,
+        on_sent: F,
+    ) -> Result<TxHash>
+    where
+        F: FnMut(&[TxHash]) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        match self.replacement_policy() {
+            Some(policy) => {
+                replace_until_included_tracked(
+                    self.destination_provider(),
+                    sent,
+                    &policy,
+                    TxKind::Mint,
+                    on_sent,
+                )
+                .await
+            }
+            None => newest(&sent),
+        }
+    }
+}
+
+/// The last of `sent`, which must not be empty
+fn newest(sent: &[TxHash]) -> Result<TxHash> {
+    sent.last().copied().ok_or_else(|| {
+        CctpError::InvalidConfig("no transaction to watch for replacement".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mocked;
+    use alloy_primitives::{Address, U64};
+    use alloy_rpc_types::TransactionRequest;
+    use serde_json::json;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn eip1559(max_fee: u128, priority_fee: u128) -> TransactionRequest {
+        TransactionRequest::default()
+            .max_fee_per_gas(max_fee)
+            .max_priority_fee_per_gas(priority_fee)
+    }
+
+    fn pending_tx(from: Address, nonce: u64, priority_fee: u128) -> serde_json::Value {
+        json!({
+            "type": "0x2",
+            "chainId": "0x1",
+            "nonce": format!("{nonce:#x}"),
+            "gas": "0x30d40",
+            "maxFeePerGas": format!("{:#x}", 10 * GWEI),
+            "maxPriorityFeePerGas": format!("{priority_fee:#x}"),
+            "to": Address::repeat_byte(0x0b),
+            "value": "0x0",
+            "input": "0x",
+            "accessList": [],
+            "r": "0x1",
+            "s": "0x1",
+            "yParity": "0x0",
+            "v": "0x0",
+            "hash": TxHash::repeat_byte(0xaa),
+            "from": from,
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null
+        })
+    }
+
+    fn policy() -> ReplacementPolicy {
+        ReplacementPolicy::default().with_interval_secs(0)
+    }
+
+    #[test]
+    fn test_bump_fees_steps_until_multiplier_limit() {
+        let policy = ReplacementPolicy::default().with_max_multiplier_percent(150);
+        let original = eip1559(10 * GWEI, GWEI);
+        let mut current = original.clone();
+
+        assert!(policy.bump_fees(&original, &mut current));
+        assert_eq!(current.max_fee_per_gas, Some(11_500_000_000));
+        assert_eq!(current.max_priority_fee_per_gas, Some(1_150_000_000));
+
+        assert!(policy.bump_fees(&original, &mut current));
+        assert_eq!(current.max_fee_per_gas, Some(13_225_000_000));
+
+        // 15.2 gwei would exceed 1.5x the original max fee
+        assert!(!policy.bump_fees(&original, &mut current));
+        assert_eq!(current.max_fee_per_gas, Some(13_225_000_000));
+    }
+
+    #[test]
+    fn test_bump_fees_respects_fee_cap() {
+        let policy = ReplacementPolicy::default().with_fee_cap(11 * GWEI);
+        let original = eip1559(10 * GWEI, GWEI);
+        let mut current = original.clone();
+
+        assert!(!policy.bump_fees(&original, &mut current));
+        assert_eq!(current, original);
+    }
+
+    #[test]
+    fn test_bump_fees_raises_zero_tip() {
+        let policy = ReplacementPolicy::default();
+        let original = eip1559(10 * GWEI, 0);
+        let mut current = original.clone();
+
+        assert!(policy.bump_fees(&original, &mut current));
+        assert_eq!(current.max_fee_per_gas, Some(11_500_000_000));
+        assert_eq!(current.max_priority_fee_per_gas, Some(1));
+        assert!(policy.bump_fees(&original, &mut current));
+        assert_eq!(current.max_priority_fee_per_gas, Some(2));
+    }
+
+    #[test]
+    fn test_bump_fees_legacy_gas_price() {
+        let policy = ReplacementPolicy::default();
+        let original = TransactionRequest::default().gas_price(20 * GWEI);
+        let mut current = original.clone();
+
+        assert!(policy.bump_fees(&original, &mut current));
+        assert_eq!(current.gas_price, Some(23 * GWEI));
+        assert!(!policy.bump_fees(&original, &mut TransactionRequest::default()));
+    }
+
+    #[tokio::test]
+    async fn test_replacement_is_reported_once_included() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+        let original = TxHash::repeat_byte(0xaa);
+        let replacement = TxHash::repeat_byte(0xbb);
+
+        asserter.push_success(&pending_tx(from, 5, GWEI));
+        // Round 0: still pending, nonce unused, nothing to replace yet
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&U64::from(5));
+        // Round 1: still pending, nonce unused, replacement sent
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&U64::from(5));
+        asserter.push_success(&replacement);
+        // Round 2: the replacement was mined
+        asserter.push_success(&json!({ "logs": [] }));
+
+        let included = replace_until_included(&provider, original, &policy(), TxKind::Burn)
+            .await
+            .unwrap();
+        assert_eq!(included, replacement);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nonce_used_elsewhere_is_an_error() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+
+        asserter.push_success(&pending_tx(from, 5, GWEI));
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&U64::from(6));
+        asserter.push_success(&serde_json::Value::Null);
+
+        let err = replace_until_included(
+            &provider,
+            TxHash::repeat_byte(0xaa),
+            &policy(),
+            TxKind::Mint,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, CctpError::NonceConsumed { from: f, nonce: 5 } if f == from));
+    }
+
+    #[tokio::test]
+    async fn test_included_transaction_returns_without_waiting() {
+        let (asserter, provider) = mocked();
+        let original = TxHash::repeat_byte(0xaa);
+
+        asserter.push_success(&pending_tx(Address::repeat_byte(0x01), 5, GWEI));
+        asserter.push_success(&json!({ "logs": [] }));
+
+        // The default policy waits 60 seconds between rounds
+        let included = tokio::time::timeout(
+            Duration::from_secs(5),
+            replace_until_included(
+                &provider,
+                original,
+                &ReplacementPolicy::default(),
+                TxKind::Burn,
+            ),
+        )
+        .await
+        .expect("an included transaction is reported before any wait")
+        .unwrap();
+        assert_eq!(included, original);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_rounds() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+        let original = TxHash::repeat_byte(0xaa);
+
+        // At the fee cap already, so nothing is ever re-sent
+        asserter.push_success(&pending_tx(from, 5, GWEI));
+        for _ in 0..3 {
+            asserter.push_success(&serde_json::Value::Null);
+            asserter.push_success(&U64::from(5));
+        }
+
+        let policy = policy().with_fee_cap(10 * GWEI).with_max_rounds(2);
+        let err = replace_until_included(&provider, original, &policy, TxKind::Mint)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CctpError::ReplacementRoundsExhausted { tx_hash, rounds: 2 } if tx_hash == original
+        ));
+        assert!(err.is_timeout());
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_zero_tip_transaction_is_replaced() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+        let replacement = TxHash::repeat_byte(0xbb);
+
+        asserter.push_success(&pending_tx(from, 5, 0));
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&U64::from(5));
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&U64::from(5));
+        asserter.push_success(&replacement);
+        asserter.push_success(&json!({ "logs": [] }));
+
+        let included = replace_until_included(
+            &provider,
+            TxHash::repeat_byte(0xaa),
+            &policy(),
+            TxKind::Burn,
+        )
+        .await
+        .unwrap();
+        assert_eq!(included, replacement);
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resumes_from_newest_known_replacement() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+        let (original, replacement) = (TxHash::repeat_byte(0xaa), TxHash::repeat_byte(0xbb));
+
+        // The replacement is the one the node still has, and it was mined
+        asserter.push_success(&pending_tx(from, 5, GWEI));
+        asserter.push_success(&json!({ "logs": [] }));
+
+        let mut reported = Vec::new();
+        let included = replace_until_included_tracked(
+            &provider,
+            vec![original, replacement],
+            &policy(),
+            TxKind::Burn,
+            |sent: &[TxHash]| {
+                reported.push(sent.to_vec());
+                ready(Ok(()))
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(included, replacement);
+        assert!(reported.is_empty());
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reports_every_replacement_sent() {
+        let (asserter, provider) = mocked();
+        let from = Address::repeat_byte(0x01);
+        let (original, replacement) = (TxHash::repeat_byte(0xaa), TxHash::repeat_byte(0xbb));
+
+        // The newest hash was dropped, so fees are bumped from the original
+        asserter.push_success(&serde_json::Value::Null);
+        asserter.push_success(&pending_tx(from, 5, GWEI));
+        for _ in 0..2 {
+            asserter.push_success(&serde_json::Value::Null);
+            asserter.push_success(&serde_json::Value::Null);
+            asserter.push_success(&U64::from(5));
+        }
+        asserter.push_success(&replacement);
+        asserter.push_success(&json!({ "logs": [] }));
+
+        let dropped = TxHash::repeat_byte(0xa1);
+        let mut reported = Vec::new();
+        let included = replace_until_included_tracked(
+            &provider,
+            vec![original, dropped],
+            &policy(),
+            TxKind::Mint,
+            |sent: &[TxHash]| {
+                reported.push(sent.to_vec());
+                ready(Ok(()))
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(included, replacement);
+        assert_eq!(reported, vec![vec![original, dropped, replacement]]);
+        assert!(asserter.read_q().is_empty());
+    }
+}
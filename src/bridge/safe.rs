@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Safe multisig export of bridge actions.
+//!
+//! [`CctpV2::safe_burn_batch`] and [`CctpV2::safe_mint_batch`] build the same
+//! calls [`CctpV2::burn`] and [`CctpV2::mint`] would send, as a
+//! [`SafeBatch`]. Signers can load it in the Safe{Wallet} Transaction Builder
+//! with [`SafeBatch::to_json`], or propose it as a single Safe transaction
+//! that `DELEGATECALL`s [`MULTI_SEND_CALL_ONLY`] with
+//! [`SafeBatch::multi_send_calldata`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let batch = bridge.safe_burn_batch(amount, safe, usdc)?;
+//! std::fs::write("cctp-burn.json", batch.to_json()?)?;
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloy_chains::Chain;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{address, Address, Bytes, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+
+use super::v2::CctpV2;
+use crate::error::{CctpError, Result};
+
+/// `MultiSendCallOnly` v1.3.0, deployed at the same address on every chain
+/// Safe supports.
+///
+/// A Safe must reach it with `DELEGATECALL` (operation `1`).
+pub const MULTI_SEND_CALL_ONLY: Address = address!("40A2aCCbd92BCA938b02010E17A5b8929b49130D");
+
+/// Transaction Builder file format version written by [`SafeBatch`].
+const SAFE_BATCH_VERSION: &str = "1.0";
+
+/// One call of a [`SafeBatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeTransaction {
+    /// Contract to call
+    pub to: Address,
+    /// Native value to send, in wei
+    #[serde(with = "as_string")]
+    pub value: U256,
+    /// ABI-encoded calldata
+    pub data: Bytes,
+}
+
+impl SafeTransaction {
+    /// Take the target, value and calldata of a transaction request
+    ///
+    /// Works with the request type of any network. Gas, fee and nonce fields
+    /// are dropped since the Safe sets its own.
+    pub fn from_request<T: TransactionBuilder>(tx_request: &T) -> Result<Self> {
+        let to = tx_request.to().ok_or_else(|| {
+            CctpError::InvalidConfig("Safe transactions cannot create contracts".to_string())
+        })?;
+
+        Ok(Self {
+            to,
+            value: tx_request.value().unwrap_or_default(),
+            data: tx_request.input().cloned().unwrap_or_default(),
+        })
+    }
+}
+
+/// Descriptive metadata shown by the Transaction Builder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeBatchMeta {
+    /// Short name of the batch
+    pub name: String,
+    /// Human-readable summary of what the batch does
+    pub description: String,
+    /// The Safe that executes the batch
+    pub created_from_safe_address: Address,
+}
+
+/// A Safe{Wallet} Transaction Builder batch file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeBatch {
+    /// File format version
+    pub version: String,
+    /// Chain the batch executes on
+    #[serde(with = "as_string")]
+    pub chain_id: u64,
+    /// Creation time, in milliseconds since the Unix epoch
+    pub created_at: u64,
+    /// Name and description shown to signers
+    pub meta: SafeBatchMeta,
+    /// The calls, executed in order
+    pub transactions: Vec<SafeTransaction>,
+}
+
+impl SafeBatch {
+    /// Create a batch for `safe` on `chain`, timestamped now
+    pub fn new(
+        chain: impl Into<Chain>,
+        safe: Address,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        transactions: Vec<SafeTransaction>,
+    ) -> Self {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+
+        Self {
+            version: SAFE_BATCH_VERSION.to_string(),
+            chain_id: chain.into().id(),
+            created_at,
+            meta: SafeBatchMeta {
+                name: name.into(),
+                description: description.into(),
+                created_from_safe_address: safe,
+            },
+            transactions,
+        }
+    }
+
+    /// Render the batch as Transaction Builder JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Calldata of `multiSend(bytes)` executing every call of the batch
+    ///
+    /// Propose it as one Safe transaction to [`MULTI_SEND_CALL_ONLY`] with
+    /// operation `DELEGATECALL`.
+    pub fn multi_send_calldata(&self) -> Bytes {
+        encode_multi_send(&self.transactions)
+    }
+}
+
+/// Encode `transactions` as `multiSend(bytes)` calldata.
+///
+/// Each call is packed as `operation (uint8) ‖ to (address) ‖ value (uint256)
+/// ‖ data length (uint256) ‖ data`, with operation `0` (`CALL`).
+pub fn encode_multi_send(transactions: &[SafeTransaction]) -> Bytes {
+    let mut packed = Vec::new();
+    for tx in transactions {
+        packed.push(0u8);
+        packed.extend_from_slice(tx.to.as_slice());
+        packed.extend_from_slice(&tx.value.to_be_bytes::<32>());
+        packed.extend_from_slice(&U256::from(tx.data.len()).to_be_bytes::<32>());
+        packed.extend_from_slice(&tx.data);
+    }
+
+    MultiSend::multiSendCall {
+        transactions: packed.into(),
+    }
+    .abi_encode()
+    .into()
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Export an approval plus the burn [`Self::burn`] would send as a Safe batch
+    ///
+    /// The burn uses this bridge's destination domain, recipient, fast-transfer
+    /// fee, hooks and finality threshold, with `safe` as the sender. The
+    /// approval covers exactly `amount`.
+    pub fn safe_burn_batch(
+        &self,
+        amount: U256,
+        safe: Address,
+        token_address: Address,
+    ) -> Result<SafeBatch> {
+        let [approval, burn] = self.approve_and_burn_requests(amount, safe, token_address)?;
+
+        let description = format!(
+            "Approve and burn {amount} of {token_address} on {} for {} on domain {}, finality threshold {}{}",
+            self.source_chain(),
+            self.recipient(),
+            self.destination_domain_id()?,
+            self.finality_threshold().as_u32(),
+            if self.hook_data().is_some() { ", with hooks" } else { "" },
+        );
+
+        Ok(SafeBatch::new(
+            *self.source_chain(),
+            safe,
+            "CCTP v2 burn",
+            description,
+            vec![
+                SafeTransaction::from_request(&approval)?,
+                SafeTransaction::from_request(&burn)?,
+            ],
+        ))
+    }
+
+    /// Export the mint [`Self::mint`] would send as a Safe batch
+    pub fn safe_mint_batch(
+        &self,
+        message_bytes: &[u8],
+        attestation: &[u8],
+        safe: Address,
+    ) -> Result<SafeBatch> {
+        let mint = self.mint_transaction_request(message_bytes, attestation, safe)?;
+
+        Ok(SafeBatch::new(
+            *self.destination_chain(),
+            safe,
+            "CCTP v2 mint",
+            format!("Receive a CCTP v2 message on {}", self.destination_chain()),
+            vec![SafeTransaction::from_request(&mint)?],
+        ))
+    }
+}
+
+/// Serializes numbers as decimal strings, as the Transaction Builder expects.
+mod as_string {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(super) fn deserialize<'de, T, D>(deserializer: D) -> std::result::Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+sol! {
+    #[allow(missing_docs)]
+    interface MultiSend {
+        function multiSend(bytes transactions) external payable;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::offline_bridge;
+    use alloy_chains::NamedChain;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_encode_multi_send_packs_calls() {
+        let transactions = vec![
+            SafeTransaction {
+                to: Address::repeat_byte(0x11),
+                value: U256::ZERO,
+                data: Bytes::from(vec![0xaa, 0xbb]),
+            },
+            SafeTransaction {
+                to: Address::repeat_byte(0x22),
+                value: U256::from(1),
+                data: Bytes::new(),
+            },
+        ];
+
+        let calldata = encode_multi_send(&transactions);
+        assert_eq!(calldata[..4], hex!("8d80ff0a"));
+
+        let decoded = MultiSend::multiSendCall::abi_decode(&calldata).unwrap();
+        let packed = decoded.transactions;
+        assert_eq!(packed.len(), 2 * (1 + 20 + 32 + 32) + 2);
+        assert_eq!(packed[0], 0);
+        assert_eq!(&packed[1..21], Address::repeat_byte(0x11).as_slice());
+        assert_eq!(packed[84], 2);
+        assert_eq!(&packed[85..87], &[0xaa, 0xbb]);
+        assert_eq!(packed[87], 0);
+        assert_eq!(&packed[88..108], Address::repeat_byte(0x22).as_slice());
+        assert_eq!(packed[139], 1);
+    }
+
+    #[test]
+    fn test_safe_burn_batch_matches_burn() {
+        let bridge = offline_bridge();
+        let safe = Address::repeat_byte(0x5a);
+        let usdc = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let amount = U256::from(1_000_000);
+
+        let batch = bridge.safe_burn_batch(amount, safe, usdc).unwrap();
+        let burn = bridge.burn_transaction_request(amount, safe, usdc).unwrap();
+
+        assert_eq!(batch.chain_id, 1);
+        assert_eq!(batch.meta.created_from_safe_address, safe);
+        assert_eq!(batch.transactions.len(), 2);
+        assert_eq!(batch.transactions[0].to, usdc);
+        assert_eq!(
+            batch.transactions[1],
+            SafeTransaction::from_request(&burn).unwrap()
+        );
+        assert!(batch.meta.description.contains("domain Base (6)"));
+    }
+
+    #[test]
+    fn test_safe_batch_json_shape() {
+        let mut batch = SafeBatch::new(
+            NamedChain::Base,
+            Address::repeat_byte(0x5a),
+            "CCTP v2 mint",
+            "Receive a CCTP v2 message on base",
+            vec![SafeTransaction {
+                to: Address::repeat_byte(0x11),
+                value: U256::from(10),
+                data: Bytes::from(vec![0x01]),
+            }],
+        );
+        batch.created_at = 1_700_000_000_000;
+
+        let json = batch.to_json().unwrap();
+        insta::assert_snapshot!(json, @r#"
+        {
+          "version": "1.0",
+          "chainId": "8453",
+          "createdAt": 1700000000000,
+          "meta": {
+            "name": "CCTP v2 mint",
+            "description": "Receive a CCTP v2 message on base",
+            "createdFromSafeAddress": "0x5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a"
+          },
+          "transactions": [
+            {
+              "to": "0x1111111111111111111111111111111111111111",
+              "value": "10",
+              "data": "0x01"
+            }
+          ]
+        }
+        "#);
+        assert_eq!(serde_json::from_str::<SafeBatch>(&json).unwrap(), batch);
+    }
+}
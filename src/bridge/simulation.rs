@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pre-flight `eth_call` simulation and gas estimation of bridge transactions.
+
+use crate::error::{CctpError, ContractRevert, Result};
+use alloy_network::Network;
+use alloy_provider::Provider;
+use tracing::{debug, warn};
+
+/// Dry-runs `tx_request` with `eth_call` against the latest block.
+///
+/// Reverts are classified into [`CctpError::SimulationReverted`]; any other
+/// RPC failure is returned as [`CctpError::Rpc`].
+pub(crate) async fn simulate_call<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_request: N::TransactionRequest,
+    kind: &'static str,
+) -> Result<()> {
+    match provider.call(tx_request).await {
+        Ok(_) => {
+            debug!(kind = kind, event = "simulation_succeeded");
+            Ok(())
+        }
+        Err(e) => match ContractRevert::from_rpc_error(&e) {
+            Some(revert) => {
+                warn!(
+                    kind = kind,
+                    reason = %revert,
+                    event = "simulation_reverted"
+                );
+                Err(CctpError::SimulationReverted(revert))
+            }
+            None => Err(CctpError::Rpc(e)),
+        },
+    }
+}
+
+/// Estimates the gas of `tx_request`, classifying reverts like [`simulate_call`].
+pub(crate) async fn estimate_gas<P: Provider<N>, N: Network>(
+    provider: &P,
+    tx_request: N::TransactionRequest,
+    kind: &'static str,
+) -> Result<u64> {
+    match provider.estimate_gas(tx_request).await {
+        Ok(gas) => {
+            debug!(kind = kind, gas = gas, event = "gas_estimated");
+            Ok(gas)
+        }
+        Err(e) => match ContractRevert::from_rpc_error(&e) {
+            Some(revert) => {
+                warn!(
+                    kind = kind,
+                    reason = %revert,
+                    event = "gas_estimation_reverted"
+                );
+                Err(CctpError::SimulationReverted(revert))
+            }
+            None => Err(CctpError::Rpc(e)),
+        },
+    }
+}
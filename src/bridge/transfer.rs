@@ -0,0 +1,966 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resumable, persisted cross-chain transfers.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+
+use alloy_chains::Chain;
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+use alloy_provider::Provider;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use super::config::PollingConfig;
+use super::v2::{CctpV2, MintResult};
+use crate::error::{CctpError, Result};
+
+/// A step of a [`Transfer`], persisted inside a [`TransferRecord`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TransferState {
+    /// Nothing has been sent yet.
+    Planned,
+    /// The `TokenMessengerV2` allowance covers the amount.
+    ///
+    /// `approval_tx` is `None` when the existing allowance was already sufficient.
+    Approved {
+        /// The approval transaction, if one was sent
+        approval_tx: Option<TxHash>,
+    },
+    /// The burn was sent on the source chain.
+    Burned {
+        /// The burn transaction on the source chain, as first sent
+        burn_tx: TxHash,
+        /// Fee-bumped replacements of `burn_tx` sent so far, oldest first
+        ///
+        /// Saved as each is sent under the bridge's
+        /// [`replacement_policy`](super::CctpV2::replacement_policy), so a
+        /// resumed transfer keeps watching the newest one.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        replacements: Vec<TxHash>,
+    },
+    /// Circle's attestation for the burn is available.
+    Attested {
+        /// The burn transaction on the source chain
+        burn_tx: TxHash,
+        /// The canonical message returned by Circle's API (nonce filled in)
+        message: Bytes,
+        /// Circle's attestation for `message`
+        attestation: Bytes,
+        /// The mint transaction and its replacements sent so far, oldest
+        /// first
+        ///
+        /// Empty until the mint is sent. A resumed transfer watches these
+        /// instead of minting again.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        mint_txs: Vec<TxHash>,
+    },
+    /// We minted on the destination chain.
+    Minted {
+        /// The burn transaction on the source chain
+        burn_tx: TxHash,
+        /// The mint transaction on the destination chain
+        mint_tx: TxHash,
+    },
+    /// A third-party relayer minted on the destination chain.
+    AlreadyRelayed {
+        /// The burn transaction on the source chain
+        burn_tx: TxHash,
+    },
+    /// A step failed. Resuming retries from `previous`.
+    Failed {
+        /// The last successfully reached state
+        previous: Box<TransferState>,
+        /// The error that stopped the transfer, rendered for persistence
+        reason: String,
+    },
+}
+
+impl TransferState {
+    /// Returns true when the transfer has completed, by us or by a relayer.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Self::Minted { .. } | Self::AlreadyRelayed { .. })
+    }
+
+    /// Returns true when the last step failed.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed { .. })
+    }
+
+    /// Returns the burn transaction hash once the burn has been sent.
+    ///
+    /// Before attestation this is the newest replacement sent, if any; after
+    /// it, the burn that was included.
+    pub fn burn_tx(&self) -> Option<TxHash> {
+        match self {
+            Self::Planned | Self::Approved { .. } => None,
+            Self::Burned {
+                burn_tx,
+                replacements,
+            } => Some(*replacements.last().unwrap_or(burn_tx)),
+            Self::Attested { burn_tx, .. }
+            | Self::Minted { burn_tx, .. }
+            | Self::AlreadyRelayed { burn_tx } => Some(*burn_tx),
+            Self::Failed { previous, .. } => previous.burn_tx(),
+        }
+    }
+
+    /// Returns the state a resumed transfer continues from.
+    ///
+    /// This is the state itself, except for [`Self::Failed`], which resumes
+    /// from the last successfully reached state.
+    pub fn resume_point(&self) -> &TransferState {
+        match self {
+            Self::Failed { previous, .. } => previous.resume_point(),
+            state => state,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Planned => "planned",
+            Self::Approved { .. } => "approved",
+            Self::Burned { .. } => "burned",
+            Self::Attested { .. } => "attested",
+            Self::Minted { .. } => "minted",
+            Self::AlreadyRelayed { .. } => "already_relayed",
+            Self::Failed { .. } => "failed",
+        }
+    }
+}
+
+/// Everything needed to resume a [`Transfer`], as persisted by a [`TransferStore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferRecord {
+    /// Caller-chosen identifier, used as the [`TransferStore`] key
+    pub id: String,
+    /// Chain the USDC is burned on
+    pub source_chain: Chain,
+    /// Chain the USDC is minted on
+    pub destination_chain: Chain,
+    /// Address that signs the approval and burn, and the mint unless the
+    /// bridge has a [`destination_signer`](super::CctpV2::destination_signer)
+    pub from: Address,
+    /// Recipient of the minted USDC
+    pub recipient: Address,
+    /// USDC token contract address on the source chain
+    pub token_address: Address,
+    /// Amount of USDC to transfer (in atomic units)
+    pub amount: U256,
+    /// Current step of the transfer
+    pub state: TransferState,
+}
+
+/// Persistence for [`TransferRecord`]s.
+///
+/// Implement this over your database or key-value store. [`Transfer`] calls
+/// [`save`](Self::save) after every state transition, so an implementation
+/// should overwrite any existing record with the same `id`.
+#[async_trait]
+pub trait TransferStore: Send + Sync {
+    /// Insert or overwrite the record with `record.id`
+    async fn save(&self, record: &TransferRecord) -> Result<()>;
+
+    /// Load the record with the given id, if one was saved
+    async fn load(&self, id: &str) -> Result<Option<TransferRecord>>;
+}
+
+/// A process-local [`TransferStore`], mainly useful for tests and examples.
+///
+/// Records do not survive a restart; use a durable store in production.
+#[derive(Debug, Default)]
+pub struct InMemoryTransferStore {
+    records: Mutex<HashMap<String, TransferRecord>>,
+}
+
+#[async_trait]
+impl TransferStore for InMemoryTransferStore {
+    async fn save(&self, record: &TransferRecord) -> Result<()> {
+        self.records
+            .lock()
+            .expect("transfer store lock poisoned")
+            .insert(record.id.clone(), record.clone());
+        Ok(())
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<TransferRecord>> {
+        Ok(self
+            .records
+            .lock()
+            .expect("transfer store lock poisoned")
+            .get(id)
+            .cloned())
+    }
+}
+
+/// A cross-chain transfer driven one persisted step at a time.
+///
+/// [`CctpV2::transfer`](super::CctpV2::transfer) runs burn, attestation and
+/// mint as one future: if the process dies after the burn, everything the
+/// caller knows about the transfer is whatever they logged. [`Transfer`] runs
+/// the same flow as an explicit state machine,
+///
+/// ```text
+/// Planned → Approved → Burned → Attested → Minted
+///                                        ↘ AlreadyRelayed
+/// (any step) → Failed
+/// ```
+///
+/// and writes a serializable [`TransferRecord`] to a [`TransferStore`] after
+/// every transition. After a crash, load the record and hand it to
+/// [`Transfer::resume`] to continue from the last completed step.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cctp_rs::{InMemoryTransferStore, Transfer};
+///
+/// let store = InMemoryTransferStore::default();
+/// let transfer = Transfer::new(&bridge, &store, "payout-42", amount, from, usdc);
+/// let record = transfer.run().await?;
+///
+/// // ...after a restart:
+/// let record = store.load("payout-42").await?.expect("record was saved");
+/// let record = Transfer::resume(&bridge, &store, record)?.run().await?;
+/// ```
+///
+/// # Crash Windows
+///
+/// A record is saved immediately after each transaction is accepted by the
+/// node, including every fee-bumped replacement and the mint before its
+/// inclusion is awaited. A crash between sending a transaction and saving
+/// the record cannot be detected from the record alone. Resuming from `Approved` after
+/// such a crash sends a second burn. Services that cannot tolerate that
+/// should check the sender's recent burns before resuming an `Approved`
+/// record.
+pub struct Transfer<
+    'a,
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    S: TransferStore,
+    SN: Network = Ethereum,
+    DN: Network = SN,
+> {
+    bridge: &'a CctpV2<SP, DP, SN, DN>,
+    store: &'a S,
+    record: TransferRecord,
+    polling_config: PollingConfig,
+}
+
+impl<'a, SP, DP, S, SN, DN> Transfer<'a, SP, DP, S, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    S: TransferStore,
+    SN: Network,
+    DN: Network,
+{
+    /// Plan a new transfer on `bridge`, persisted under `id`.
+    ///
+    /// Nothing is sent or saved until [`Self::step`] or [`Self::run`] is called.
+    pub fn new(
+        bridge: &'a CctpV2<SP, DP, SN, DN>,
+        store: &'a S,
+        id: impl Into<String>,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Self {
+        let record = TransferRecord {
+            id: id.into(),
+            source_chain: *bridge.source_chain(),
+            destination_chain: *bridge.destination_chain(),
+            from,
+            recipient: *bridge.recipient(),
+            token_address,
+            amount,
+            state: TransferState::Planned,
+        };
+
+        Self {
+            bridge,
+            store,
+            polling_config: default_polling_config(bridge),
+            record,
+        }
+    }
+
+    /// Track a transfer whose burn was signed and sent outside the bridge.
+    ///
+    /// Use this after submitting a burn prepared with
+    /// [`CctpV2::prepare_burn`](super::CctpV2::prepare_burn) through an
+    /// external signer: the transfer starts in [`TransferState::Burned`], so
+    /// [`Self::run`] continues with attestation and mint.
+    pub fn from_burn(
+        bridge: &'a CctpV2<SP, DP, SN, DN>,
+        store: &'a S,
+        id: impl Into<String>,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        burn_tx: TxHash,
+    ) -> Self {
+        let mut transfer = Self::new(bridge, store, id, amount, from, token_address);
+        transfer.record.state = TransferState::Burned {
+            burn_tx,
+            replacements: Vec::new(),
+        };
+        transfer
+    }
+
+    /// Continue a transfer from a previously saved record.
+    ///
+    /// A [`TransferState::Failed`] record resumes from the last successfully
+    /// reached state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CctpError::InvalidConfig` if the record was created for a
+    /// different chain pair or recipient than `bridge` is configured with.
+    pub fn resume(
+        bridge: &'a CctpV2<SP, DP, SN, DN>,
+        store: &'a S,
+        mut record: TransferRecord,
+    ) -> Result<Self> {
+        if record.source_chain != *bridge.source_chain()
+            || record.destination_chain != *bridge.destination_chain()
+            || record.recipient != *bridge.recipient()
+        {
+            return Err(CctpError::InvalidConfig(format!(
+                "transfer {} was planned for {} -> {} (recipient {}), but the bridge is configured for {} -> {} (recipient {})",
+                record.id,
+                record.source_chain,
+                record.destination_chain,
+                record.recipient,
+                bridge.source_chain(),
+                bridge.destination_chain(),
+                bridge.recipient(),
+            )));
+        }
+
+        record.state = record.state.resume_point().clone();
+
+        info!(
+            transfer_id = %record.id,
+            state = record.state.name(),
+            event = "transfer_resumed"
+        );
+
+        Ok(Self {
+            bridge,
+            store,
+            polling_config: default_polling_config(bridge),
+            record,
+        })
+    }
+
+    /// Override the attestation polling configuration.
+    ///
+    /// Defaults to [`PollingConfig::fast_transfer`] for fast-transfer bridges
+    /// and [`PollingConfig::default`] otherwise, matching `CctpV2::transfer`.
+    pub fn with_polling_config(mut self, polling_config: PollingConfig) -> Self {
+        self.polling_config = polling_config;
+        self
+    }
+
+    /// Returns the current record.
+    pub fn record(&self) -> &TransferRecord {
+        &self.record
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> &TransferState {
+        &self.record.state
+    }
+
+    /// Advance by one step and persist the new state.
+    ///
+    /// Calling this on a completed transfer is a no-op. If the step fails, the
+    /// record is saved as [`TransferState::Failed`] before the error is returned.
+    pub async fn step(&mut self) -> Result<&TransferState> {
+        let next = match self.advance().await {
+            Ok(Some(next)) => next,
+            Ok(None) => return Ok(&self.record.state),
+            Err(e) => {
+                error!(
+                    transfer_id = %self.record.id,
+                    state = self.record.state.name(),
+                    error = %e,
+                    event = "transfer_step_failed"
+                );
+                // A retried step replaces the failure instead of nesting it
+                let previous = self.record.state.resume_point().clone();
+                self.record.state = TransferState::Failed {
+                    previous: Box::new(previous),
+                    reason: e.to_string(),
+                };
+                self.store.save(&self.record).await?;
+                return Err(e);
+            }
+        };
+
+        self.record.state = next;
+        self.store.save(&self.record).await?;
+
+        info!(
+            transfer_id = %self.record.id,
+            state = self.record.state.name(),
+            event = "transfer_state_saved"
+        );
+
+        Ok(&self.record.state)
+    }
+
+    /// Drive the transfer to completion, persisting after every step.
+    ///
+    /// Returns the final record, in [`TransferState::Minted`] or
+    /// [`TransferState::AlreadyRelayed`].
+    pub async fn run(mut self) -> Result<TransferRecord> {
+        while !self.record.state.is_complete() {
+            self.step().await?;
+        }
+        Ok(self.record)
+    }
+
+    /// Perform the side effect for the current state and return the next one.
+    ///
+    /// Replacement hashes are saved as they are sent, before the step
+    /// completes. Returns `None` for terminal states.
+    async fn advance(&mut self) -> Result<Option<TransferState>> {
+        let bridge = self.bridge;
+        let store = self.store;
+        let state = self.record.state.resume_point().clone();
+        let record = &mut self.record;
+
+        Ok(Some(match state {
+            TransferState::Planned => {
+                let approval_tx = bridge
+                    .ensure_approval(record.token_address, record.from, record.amount)
+                    .await?;
+                TransferState::Approved { approval_tx }
+            }
+            TransferState::Approved { .. } => {
+                let burn_tx = bridge
+                    .burn(record.amount, record.from, record.token_address)
+                    .await?;
+                TransferState::Burned {
+                    burn_tx,
+                    replacements: Vec::new(),
+                }
+            }
+            TransferState::Burned {
+                burn_tx,
+                replacements,
+            } => {
+                let sent = [vec![burn_tx], replacements].concat();
+                let burn_tx = bridge
+                    .included_burn_tracked(sent, |sent| {
+                        record.state = TransferState::Burned {
+                            burn_tx: sent[0],
+                            replacements: sent[1..].to_vec(),
+                        };
+                        save_snapshot(store, record)
+                    })
+                    .await?;
+                if bridge.confirmations().is_some() {
+                    bridge.wait_for_burn_receipt(burn_tx).await?;
+                }
+                let (message, attestation) =
+                    bridge.get_attestation(burn_tx, self.polling_config).await?;
+                TransferState::Attested {
+                    burn_tx,
+                    message: message.into(),
+                    attestation: attestation.into(),
+                    mint_txs: Vec::new(),
+                }
+            }
+            TransferState::Attested {
+                burn_tx,
+                message,
+                attestation,
+                mint_txs,
+            } => {
+                let attested = |mint_txs: &[TxHash]| TransferState::Attested {
+                    burn_tx,
+                    message: message.clone(),
+                    attestation: attestation.clone(),
+                    mint_txs: mint_txs.to_vec(),
+                };
+                let sent = if mint_txs.is_empty() {
+                    match bridge
+                        .mint_if_needed(
+                            message.to_vec(),
+                            attestation.to_vec(),
+                            bridge.mint_sender(record.from),
+                        )
+                        .await?
+                    {
+                        MintResult::Minted(mint_tx) => {
+                            record.state = attested(&[mint_tx]);
+                            save_snapshot(store, record).await?;
+                            vec![mint_tx]
+                        }
+                        MintResult::AlreadyRelayed => {
+                            return Ok(Some(TransferState::AlreadyRelayed { burn_tx }));
+                        }
+                    }
+                } else {
+                    mint_txs
+                };
+                let mint_tx = bridge
+                    .included_mint_tracked(sent, |sent| {
+                        record.state = attested(sent);
+                        save_snapshot(store, record)
+                    })
+                    .await?;
+                if bridge.confirmations().is_some() {
+                    match bridge.wait_for_mint_receipt(mint_tx).await {
+                        Ok(_) => {}
+                        Err(e @ CctpError::MintReverted(_)) if e.is_already_relayed() => {
+                            return Ok(Some(TransferState::AlreadyRelayed { burn_tx }));
+                        }
+                        Err(e @ CctpError::MintReverted(_)) => {
+                            // Forget the reverted mint so resuming sends a new one
+                            record.state = attested(&[]);
+                            return Err(e);
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                TransferState::Minted { burn_tx, mint_tx }
+            }
+            TransferState::Minted { .. }
+            | TransferState::AlreadyRelayed { .. }
+            | TransferState::Failed { .. } => return Ok(None),
+        }))
+    }
+}
+
+/// Save a copy of `record`, for use while a step is still in progress
+fn save_snapshot<'a, S: TransferStore>(
+    store: &'a S,
+    record: &TransferRecord,
+) -> impl Future<Output = Result<()>> + 'a {
+    let snapshot = record.clone();
+    async move { store.save(&snapshot).await }
+}
+
+pub(super) fn default_polling_config<SP, DP, SN, DN>(
+    bridge: &CctpV2<SP, DP, SN, DN>,
+) -> PollingConfig
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    if bridge.is_fast_transfer() {
+        PollingConfig::fast_transfer()
+    } else {
+        PollingConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mocked;
+    use crate::ReplacementPolicy;
+    use alloy_chains::NamedChain;
+    use alloy_provider::ProviderBuilder;
+
+    fn record(state: TransferState) -> TransferRecord {
+        TransferRecord {
+            id: "transfer-1".to_string(),
+            source_chain: NamedChain::Mainnet.into(),
+            destination_chain: NamedChain::Base.into(),
+            from: Address::repeat_byte(0x01),
+            recipient: Address::repeat_byte(0x02),
+            token_address: Address::repeat_byte(0x03),
+            amount: U256::from(1_000_000),
+            state,
+        }
+    }
+
+    fn burned(burn_tx: TxHash) -> TransferState {
+        TransferState::Burned {
+            burn_tx,
+            replacements: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_record_serde_roundtrip() {
+        let attested = record(TransferState::Attested {
+            burn_tx: TxHash::repeat_byte(0xaa),
+            message: Bytes::from(vec![1, 2, 3]),
+            attestation: Bytes::from(vec![4, 5, 6]),
+            mint_txs: Vec::new(),
+        });
+        let json = serde_json::to_string(&attested).unwrap();
+        assert!(json.contains(r#""state":"attested""#));
+        assert!(json.contains(r#""source_chain":"mainnet""#));
+        assert_eq!(
+            serde_json::from_str::<TransferRecord>(&json).unwrap(),
+            attested
+        );
+
+        let failed = record(TransferState::Failed {
+            previous: Box::new(burned(TxHash::repeat_byte(0xbb))),
+            reason: "Timeout waiting for attestation".to_string(),
+        });
+        let json = serde_json::to_string(&failed).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransferRecord>(&json).unwrap(),
+            failed
+        );
+    }
+
+    #[test]
+    fn test_state_predicates() {
+        let burn_tx = TxHash::repeat_byte(0xaa);
+
+        assert!(!TransferState::Planned.is_complete());
+        assert!(TransferState::AlreadyRelayed { burn_tx }.is_complete());
+        assert!(TransferState::Minted {
+            burn_tx,
+            mint_tx: TxHash::ZERO
+        }
+        .is_complete());
+
+        assert_eq!(
+            TransferState::Approved { approval_tx: None }.burn_tx(),
+            None
+        );
+        assert_eq!(burned(burn_tx).burn_tx(), Some(burn_tx));
+
+        let failed = TransferState::Failed {
+            previous: Box::new(burned(burn_tx)),
+            reason: "boom".to_string(),
+        };
+        assert!(failed.is_failed());
+        assert!(!failed.is_complete());
+        assert_eq!(failed.burn_tx(), Some(burn_tx));
+        assert_eq!(failed.resume_point(), &burned(burn_tx));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_overwrites_by_id() {
+        let store = InMemoryTransferStore::default();
+        assert!(store.load("transfer-1").await.unwrap().is_none());
+
+        store.save(&record(TransferState::Planned)).await.unwrap();
+        let saved = record(burned(TxHash::repeat_byte(0xaa)));
+        store.save(&saved).await.unwrap();
+
+        assert_eq!(store.load("transfer-1").await.unwrap(), Some(saved));
+    }
+
+    #[tokio::test]
+    async fn test_completed_transfer_step_is_noop() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+        let store = InMemoryTransferStore::default();
+
+        let done = record(TransferState::AlreadyRelayed {
+            burn_tx: TxHash::repeat_byte(0xaa),
+        });
+        let finished = Transfer::resume(&bridge, &store, done.clone())
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(finished, done);
+        // Nothing to persist when no step ran
+        assert!(store.load("transfer-1").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_resume_rejects_mismatched_bridge() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Linea)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+        let store = InMemoryTransferStore::default();
+
+        let result = Transfer::resume(&bridge, &store, record(TransferState::Planned));
+        assert!(matches!(result, Err(CctpError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_resume_from_failed_restarts_previous_step() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+        let store = InMemoryTransferStore::default();
+        let burn_tx = TxHash::repeat_byte(0xaa);
+
+        let failed = record(TransferState::Failed {
+            previous: Box::new(burned(burn_tx)),
+            reason: "Timeout waiting for attestation".to_string(),
+        });
+        let transfer = Transfer::resume(&bridge, &store, failed).unwrap();
+
+        assert_eq!(transfer.state(), &burned(burn_tx));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_failure_does_not_nest() {
+        let (asserter, provider) = mocked();
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+        let store = InMemoryTransferStore::default();
+        let mut transfer =
+            Transfer::resume(&bridge, &store, record(TransferState::Planned)).unwrap();
+
+        asserter.push_failure_msg("allowance unavailable");
+        transfer.step().await.unwrap_err();
+        asserter.push_failure_msg("still unavailable");
+        transfer.step().await.unwrap_err();
+
+        let TransferState::Failed { previous, reason } = transfer.state() else {
+            panic!("expected a failed state, got {:?}", transfer.state());
+        };
+        assert_eq!(**previous, TransferState::Planned);
+        assert!(reason.contains("still unavailable"));
+        assert_eq!(
+            store.load("transfer-1").await.unwrap().as_ref(),
+            Some(transfer.record())
+        );
+    }
+
+    #[test]
+    fn test_from_burn_starts_at_burned() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+        let store = InMemoryTransferStore::default();
+        let burn_tx = TxHash::repeat_byte(0xaa);
+
+        let transfer = Transfer::from_burn(
+            &bridge,
+            &store,
+            "transfer-1",
+            U256::from(1_000_000),
+            Address::repeat_byte(0x01),
+            Address::repeat_byte(0x03),
+            burn_tx,
+        );
+
+        assert_eq!(transfer.record(), &record(burned(burn_tx)));
+    }
+
+    /// A pending EIP-1559 transaction from `0x01..01` with nonce 5
+    fn pending_tx(tx_hash: TxHash) -> serde_json::Value {
+        serde_json::json!({
+            "type": "0x2",
+            "chainId": "0x1",
+            "nonce": "0x5",
+            "gas": "0x30d40",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "to": Address::repeat_byte(0x0b),
+            "value": "0x0",
+            "input": "0x",
+            "accessList": [],
+            "r": "0x1",
+            "s": "0x1",
+            "yParity": "0x0",
+            "v": "0x0",
+            "hash": tx_hash,
+            "from": Address::repeat_byte(0x01),
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_burn_replacements_are_saved_as_sent() {
+        use alloy_primitives::U64;
+
+        let (asserter, provider) = mocked();
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .replacement_policy(ReplacementPolicy::default().with_interval_secs(0))
+            .build();
+        let store = InMemoryTransferStore::default();
+        let (burn_tx, replacement) = (TxHash::repeat_byte(0xaa), TxHash::repeat_byte(0xbb));
+        let mut transfer = Transfer::resume(&bridge, &store, record(burned(burn_tx))).unwrap();
+
+        // Pending for two rounds, replaced, then the node goes away
+        asserter.push_success(&pending_tx(burn_tx));
+        for _ in 0..2 {
+            asserter.push_success(&serde_json::Value::Null);
+            asserter.push_success(&U64::from(5));
+        }
+        asserter.push_success(&replacement);
+        asserter.push_failure_msg("connection reset");
+        transfer.step().await.unwrap_err();
+        assert!(asserter.read_q().is_empty());
+
+        let saved = store.load("transfer-1").await.unwrap().unwrap();
+        let resumed = Transfer::resume(&bridge, &store, saved).unwrap();
+        let resumed_state = TransferState::Burned {
+            burn_tx,
+            replacements: vec![replacement],
+        };
+        assert_eq!(resumed.state(), &resumed_state);
+        assert_eq!(resumed.state().burn_tx(), Some(replacement));
+    }
+
+    #[tokio::test]
+    async fn test_sent_mint_is_watched_not_resent() {
+        use alloy_primitives::U64;
+
+        let (asserter, provider) = mocked();
+        let builder = || {
+            CctpV2::builder()
+                .source_chain(NamedChain::Mainnet)
+                .destination_chain(NamedChain::Base)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::repeat_byte(0x02))
+        };
+        let store = InMemoryTransferStore::default();
+        let (burn_tx, mint_tx, replacement) = (
+            TxHash::repeat_byte(0xaa),
+            TxHash::repeat_byte(0xcc),
+            TxHash::repeat_byte(0xdd),
+        );
+        let attested = record(TransferState::Attested {
+            burn_tx,
+            message: Bytes::from(vec![1, 2, 3]),
+            attestation: Bytes::from(vec![4, 5, 6]),
+            mint_txs: vec![mint_tx],
+        });
+
+        // Without a replacement policy, the sent mint completes the transfer
+        let bridge = builder().build();
+        let finished = Transfer::resume(&bridge, &store, attested.clone())
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(finished.state, TransferState::Minted { burn_tx, mint_tx });
+        assert!(asserter.read_q().is_empty());
+
+        // With one, the sent mint is watched and replaced
+        let bridge = builder()
+            .replacement_policy(ReplacementPolicy::default().with_interval_secs(0))
+            .build();
+        asserter.push_success(&pending_tx(mint_tx));
+        for _ in 0..2 {
+            asserter.push_success(&serde_json::Value::Null);
+            asserter.push_success(&U64::from(5));
+        }
+        asserter.push_success(&replacement);
+        asserter.push_success(&serde_json::json!({ "logs": [] }));
+        let finished = Transfer::resume(&bridge, &store, attested)
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(
+            finished.state,
+            TransferState::Minted {
+                burn_tx,
+                mint_tx: replacement
+            }
+        );
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reverted_mint_is_failed_not_minted() {
+        let (asserter, provider) = mocked();
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .confirmations(1)
+            .build();
+        let store = InMemoryTransferStore::default();
+        let (burn_tx, mint_tx) = (TxHash::repeat_byte(0xaa), TxHash::repeat_byte(0xcc));
+        let attested = |mint_txs: Vec<TxHash>| TransferState::Attested {
+            burn_tx,
+            message: Bytes::from(vec![1, 2, 3]),
+            attestation: Bytes::from(vec![4, 5, 6]),
+            mint_txs,
+        };
+        let reverted = serde_json::json!({
+            "type": "0x2",
+            "status": "0x0",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "transactionHash": mint_tx,
+            "transactionIndex": "0x0",
+            "blockHash": TxHash::repeat_byte(0xbb),
+            "blockNumber": "0x10",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x1",
+            "from": Address::repeat_byte(0x01),
+            "to": Address::repeat_byte(0x0b),
+            "contractAddress": null
+        });
+
+        // Found when watched and when fetched, then not replayable
+        asserter.push_success(&reverted);
+        asserter.push_success(&reverted);
+        asserter.push_success(&serde_json::Value::Null);
+        let mut transfer =
+            Transfer::resume(&bridge, &store, record(attested(vec![mint_tx]))).unwrap();
+        let err = transfer.step().await.unwrap_err();
+        assert!(matches!(err, CctpError::MintReverted(ref tx) if tx.tx_hash == mint_tx));
+        assert!(asserter.read_q().is_empty());
+
+        // Saved as failed, resuming with a fresh mint
+        let saved = store.load("transfer-1").await.unwrap().unwrap();
+        assert!(saved.state.is_failed());
+        assert_eq!(saved.state.resume_point(), &attested(Vec::new()));
+    }
+}
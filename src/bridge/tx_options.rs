@@ -0,0 +1,464 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-transaction overrides and fee strategies.
+//!
+//! [`TxOptions`] pins the gas limit, nonce, EIP-1559 fees or access list of
+//! the transactions the bridge sends. Pass it to
+//! [`CctpV2::burn_with_options`](super::CctpV2::burn_with_options),
+//! [`mint_with_options`](super::CctpV2::mint_with_options) and
+//! [`approve_with_options`](super::CctpV2::approve_with_options), or set it
+//! once with the bridge builder's `tx_options` to apply it to every burn,
+//! mint and approval, including those sent by `transfer`.
+//!
+//! Fields left unset are filled by the provider as before. Fees can come
+//! from a [`FeeStrategy`]:
+//!
+//! | Strategy | Fees |
+//! |----------|------|
+//! | [`BufferedFees`] | Next base fee and `eth_maxPriorityFeePerGas`, buffered with [`calculate_gas_price_with_buffer`] |
+//! | [`FixedFees`] | Constant values |
+//! | [`PercentileFees`] | Median of a tip percentile over recent blocks, from `eth_feeHistory` |
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use cctp_rs::{PercentileFees, TxOptions};
+//!
+//! let options = TxOptions::builder()
+//!     .fee_strategy(Arc::new(PercentileFees::default()))
+//!     .gas_buffer_percent(25)
+//!     .build();
+//! let burn_tx = bridge.burn_with_options(amount, from, usdc, &options).await?;
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use alloy_json_rpc::RpcError;
+use alloy_network::{Ethereum, Network, TransactionBuilder};
+use alloy_primitives::U256;
+use alloy_provider::utils::Eip1559Estimation;
+use alloy_provider::Provider;
+use alloy_rpc_types::{AccessList, BlockNumberOrTag, FeeHistory};
+use async_trait::async_trait;
+use bon::Builder;
+use tracing::debug;
+
+use crate::error::{CctpError, Result};
+use crate::provider::{
+    calculate_gas_price_with_buffer, estimate_gas_with_buffer, DEFAULT_GAS_BUFFER_PERCENT,
+};
+
+/// Chooses EIP-1559 fees for a transaction about to be sent.
+///
+/// Implement this to plug in an external gas oracle or a custom policy.
+#[async_trait]
+pub trait FeeStrategy<N: Network = Ethereum>: Debug + Send + Sync {
+    /// Fees to use for the next transaction sent through `provider`
+    async fn estimate_fees(&self, provider: &dyn Provider<N>) -> Result<Eip1559Estimation>;
+}
+
+/// Overrides applied to a transaction before it is sent.
+///
+/// Explicit `max_fee_per_gas` and `max_priority_fee_per_gas` take precedence
+/// over the [`FeeStrategy`]. When only one is explicit, the strategy's other
+/// fee is adjusted so the priority fee never exceeds the max fee. An explicit
+/// `gas_limit` takes precedence over `gas_buffer_percent`.
+#[derive(Builder, Clone, Debug)]
+pub struct TxOptions<N: Network = Ethereum> {
+    /// Gas limit to use instead of the provider's estimate
+    gas_limit: Option<u64>,
+
+    /// Estimate gas and add this percentage on top, when no `gas_limit` is set
+    gas_buffer_percent: Option<u64>,
+
+    /// Nonce to use instead of the provider's
+    ///
+    /// Set this per call; a bridge-wide nonce would be reused by every
+    /// transaction.
+    nonce: Option<u64>,
+
+    /// EIP-1559 max fee per gas, in wei
+    max_fee_per_gas: Option<u128>,
+
+    /// EIP-1559 max priority fee per gas, in wei
+    max_priority_fee_per_gas: Option<u128>,
+
+    /// Strategy for the fees not set explicitly
+    fee_strategy: Option<Arc<dyn FeeStrategy<N>>>,
+
+    /// EIP-2930 access list
+    access_list: Option<AccessList>,
+}
+
+impl<N: Network> Default for TxOptions<N> {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl<N: Network> TxOptions<N> {
+    /// Returns the gas limit override
+    pub fn gas_limit(&self) -> Option<u64> {
+        self.gas_limit
+    }
+
+    /// Returns the gas estimate buffer, in percent
+    pub fn gas_buffer_percent(&self) -> Option<u64> {
+        self.gas_buffer_percent
+    }
+
+    /// Returns the nonce override
+    pub fn nonce(&self) -> Option<u64> {
+        self.nonce
+    }
+
+    /// Returns the max fee per gas override
+    pub fn max_fee_per_gas(&self) -> Option<u128> {
+        self.max_fee_per_gas
+    }
+
+    /// Returns the max priority fee per gas override
+    pub fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        self.max_priority_fee_per_gas
+    }
+
+    /// Returns the fee strategy, if any
+    pub fn fee_strategy(&self) -> Option<&Arc<dyn FeeStrategy<N>>> {
+        self.fee_strategy.as_ref()
+    }
+
+    /// Returns the access list, if any
+    pub fn access_list(&self) -> Option<&AccessList> {
+        self.access_list.as_ref()
+    }
+
+    /// These options with `gas_limit` in place of a gas estimate, unless a
+    /// gas limit is already set
+    pub(crate) fn with_gas_limit_or(&self, gas_limit: u64) -> Self {
+        Self {
+            gas_limit: Some(self.gas_limit.unwrap_or(gas_limit)),
+            ..self.clone()
+        }
+    }
+
+    /// Fill the overridden fields of `tx_request`, querying `provider` for
+    /// the fee strategy and buffered gas estimate
+    pub(crate) async fn apply<P: Provider<N>>(
+        &self,
+        provider: &P,
+        tx_request: &mut N::TransactionRequest,
+    ) -> Result<()> {
+        if let Some(nonce) = self.nonce {
+            tx_request.set_nonce(nonce);
+        }
+        if let Some(access_list) = &self.access_list {
+            tx_request.set_access_list(access_list.clone());
+        }
+
+        let needs_strategy =
+            self.max_fee_per_gas.is_none() || self.max_priority_fee_per_gas.is_none();
+        let estimated = match &self.fee_strategy {
+            Some(strategy) if needs_strategy => Some(strategy.estimate_fees(provider).await?),
+            _ => None,
+        };
+        let (max_fee_per_gas, max_priority_fee_per_gas) = match (
+            self.max_fee_per_gas,
+            self.max_priority_fee_per_gas,
+            estimated,
+        ) {
+            // A strategy tip above an explicit max fee would be rejected
+            (Some(max_fee), None, Some(fees)) => (
+                Some(max_fee),
+                Some(fees.max_priority_fee_per_gas.min(max_fee)),
+            ),
+            // and so would a strategy max fee below an explicit tip
+            (None, Some(tip), Some(fees)) => (Some(fees.max_fee_per_gas.max(tip)), Some(tip)),
+            (max_fee, tip, estimated) => (
+                max_fee.or(estimated.map(|fees| fees.max_fee_per_gas)),
+                tip.or(estimated.map(|fees| fees.max_priority_fee_per_gas)),
+            ),
+        };
+        if let Some(max_fee_per_gas) = max_fee_per_gas {
+            tx_request.set_max_fee_per_gas(max_fee_per_gas);
+        }
+        if let Some(max_priority_fee_per_gas) = max_priority_fee_per_gas {
+            tx_request.set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+        }
+
+        let gas_limit = match (self.gas_limit, self.gas_buffer_percent) {
+            (Some(gas_limit), _) => Some(gas_limit),
+            (None, Some(buffer)) => {
+                Some(estimate_gas_with_buffer(provider, tx_request, Some(buffer)).await?)
+            }
+            (None, None) => None,
+        };
+        if let Some(gas_limit) = gas_limit {
+            tx_request.set_gas_limit(gas_limit);
+        }
+
+        debug!(
+            nonce = ?self.nonce,
+            gas_limit = ?gas_limit,
+            max_fee_per_gas = ?max_fee_per_gas,
+            max_priority_fee_per_gas = ?max_priority_fee_per_gas,
+            event = "tx_options_applied"
+        );
+
+        Ok(())
+    }
+}
+
+/// Next block's base fee and the node's suggested tip, with a buffer on the tip.
+///
+/// Uses [`calculate_gas_price_with_buffer`], so the max fee is twice the base
+/// fee plus the buffered tip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferedFees {
+    /// Percentage added to the suggested priority fee
+    pub buffer_percent: u64,
+}
+
+impl Default for BufferedFees {
+    fn default() -> Self {
+        Self {
+            buffer_percent: DEFAULT_GAS_BUFFER_PERCENT,
+        }
+    }
+}
+
+#[async_trait]
+impl<N: Network> FeeStrategy<N> for BufferedFees {
+    async fn estimate_fees(&self, provider: &dyn Provider<N>) -> Result<Eip1559Estimation> {
+        let history = provider
+            .get_fee_history(1, BlockNumberOrTag::Latest, &[])
+            .await?;
+        let base_fee = next_base_fee(&history)?;
+        let priority_fee = provider.get_max_priority_fee_per_gas().await?;
+
+        let (max_fee, max_priority_fee) = calculate_gas_price_with_buffer(
+            U256::from(base_fee),
+            U256::from(priority_fee),
+            self.buffer_percent,
+        );
+
+        Ok(Eip1559Estimation {
+            max_fee_per_gas: max_fee.saturating_to(),
+            max_priority_fee_per_gas: max_priority_fee.saturating_to(),
+        })
+    }
+}
+
+/// Constant fees, regardless of network conditions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedFees {
+    /// Max fee per gas, in wei
+    pub max_fee_per_gas: u128,
+    /// Max priority fee per gas, in wei
+    pub max_priority_fee_per_gas: u128,
+}
+
+#[async_trait]
+impl<N: Network> FeeStrategy<N> for FixedFees {
+    async fn estimate_fees(&self, _provider: &dyn Provider<N>) -> Result<Eip1559Estimation> {
+        Ok(Eip1559Estimation {
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// Median of a tip percentile over recent blocks.
+///
+/// The tip is the median, across the last `block_count` blocks, of the
+/// `percentile`-th priority fee paid in each block. Empty blocks are skipped.
+/// The max fee is twice the next base fee plus that tip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PercentileFees {
+    /// Number of recent blocks to sample, at most 1024
+    pub block_count: u64,
+    /// Percentile of each block's priority fees, from 0 to 100
+    pub percentile: f64,
+}
+
+impl Default for PercentileFees {
+    fn default() -> Self {
+        Self {
+            block_count: 20,
+            percentile: 50.0,
+        }
+    }
+}
+
+#[async_trait]
+impl<N: Network> FeeStrategy<N> for PercentileFees {
+    async fn estimate_fees(&self, provider: &dyn Provider<N>) -> Result<Eip1559Estimation> {
+        let history = provider
+            .get_fee_history(
+                self.block_count,
+                BlockNumberOrTag::Latest,
+                &[self.percentile],
+            )
+            .await?;
+        let base_fee = next_base_fee(&history)?;
+
+        let mut tips: Vec<u128> = history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|block| block.first().copied())
+            .filter(|tip| *tip > 0)
+            .collect();
+        tips.sort_unstable();
+        let priority_fee = tips.get(tips.len() / 2).copied().unwrap_or_default();
+
+        Ok(Eip1559Estimation {
+            max_fee_per_gas: base_fee.saturating_mul(2).saturating_add(priority_fee),
+            max_priority_fee_per_gas: priority_fee,
+        })
+    }
+}
+
+fn next_base_fee(history: &FeeHistory) -> Result<u128> {
+    history
+        .next_block_base_fee()
+        .ok_or(CctpError::Rpc(RpcError::UnsupportedFeature("eip1559")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mocked;
+    use alloy_primitives::{U128, U64};
+    use alloy_rpc_types::TransactionRequest;
+    use rstest::rstest;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn history(base_fees: Vec<u128>, rewards: Option<Vec<Vec<u128>>>) -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: base_fees,
+            reward: rewards,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffered_fees() {
+        let (asserter, provider) = mocked();
+        asserter.push_success(&history(vec![30 * GWEI, 30 * GWEI], None));
+        asserter.push_success(&U128::from(2 * GWEI));
+
+        let fees = BufferedFees::default()
+            .estimate_fees(&provider)
+            .await
+            .unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, 2_400_000_000);
+        assert_eq!(fees.max_fee_per_gas, 62_400_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_percentile_fees_takes_median_of_non_empty_blocks() {
+        let (asserter, provider) = mocked();
+        asserter.push_success(&history(
+            vec![10 * GWEI, 10 * GWEI, 10 * GWEI, 10 * GWEI, 12 * GWEI],
+            Some(vec![vec![3 * GWEI], vec![0], vec![GWEI], vec![2 * GWEI]]),
+        ));
+
+        let fees = PercentileFees::default()
+            .estimate_fees(&provider)
+            .await
+            .unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, 2 * GWEI);
+        assert_eq!(fees.max_fee_per_gas, 26 * GWEI);
+    }
+
+    #[tokio::test]
+    async fn test_missing_base_fee_is_unsupported() {
+        let (asserter, provider) = mocked();
+        asserter.push_success(&history(vec![], None));
+
+        let err = PercentileFees::default()
+            .estimate_fees(&provider)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CctpError::Rpc(RpcError::UnsupportedFeature("eip1559"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_apply_explicit_fields_override_strategy() {
+        let (asserter, provider) = mocked();
+        let options = TxOptions::<Ethereum>::builder()
+            .nonce(7)
+            .gas_limit(150_000)
+            .gas_buffer_percent(50)
+            .max_priority_fee_per_gas(GWEI)
+            .fee_strategy(Arc::new(FixedFees {
+                max_fee_per_gas: 40 * GWEI,
+                max_priority_fee_per_gas: 3 * GWEI,
+            }))
+            .access_list(AccessList::default())
+            .build();
+
+        let mut tx_request = TransactionRequest::default();
+        options.apply(&provider, &mut tx_request).await.unwrap();
+
+        assert_eq!(tx_request.nonce, Some(7));
+        assert_eq!(tx_request.gas, Some(150_000));
+        assert_eq!(tx_request.max_fee_per_gas, Some(40 * GWEI));
+        assert_eq!(tx_request.max_priority_fee_per_gas, Some(GWEI));
+        assert_eq!(tx_request.access_list, Some(AccessList::default()));
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[rstest]
+    #[case::tip_above_explicit_max_fee(Some(2 * GWEI), None, 2 * GWEI, 2 * GWEI)]
+    #[case::tip_below_explicit_max_fee(Some(40 * GWEI), None, 40 * GWEI, 3 * GWEI)]
+    #[case::max_fee_below_explicit_tip(None, Some(50 * GWEI), 50 * GWEI, 50 * GWEI)]
+    #[case::max_fee_above_explicit_tip(None, Some(GWEI), 30 * GWEI, GWEI)]
+    #[tokio::test]
+    async fn test_apply_mixed_fees_keep_tip_within_max_fee(
+        #[case] max_fee_per_gas: Option<u128>,
+        #[case] max_priority_fee_per_gas: Option<u128>,
+        #[case] expected_max_fee: u128,
+        #[case] expected_tip: u128,
+    ) {
+        let (_asserter, provider) = mocked();
+        let options = TxOptions::<Ethereum>::builder()
+            .maybe_max_fee_per_gas(max_fee_per_gas)
+            .maybe_max_priority_fee_per_gas(max_priority_fee_per_gas)
+            .fee_strategy(Arc::new(FixedFees {
+                max_fee_per_gas: 30 * GWEI,
+                max_priority_fee_per_gas: 3 * GWEI,
+            }))
+            .build();
+
+        let mut tx_request = TransactionRequest::default();
+        options.apply(&provider, &mut tx_request).await.unwrap();
+
+        assert_eq!(tx_request.max_fee_per_gas, Some(expected_max_fee));
+        assert_eq!(tx_request.max_priority_fee_per_gas, Some(expected_tip));
+    }
+
+    #[tokio::test]
+    async fn test_apply_buffers_gas_estimate() {
+        let (asserter, provider) = mocked();
+        asserter.push_success(&U64::from(100_000));
+        let options = TxOptions::<Ethereum>::builder()
+            .gas_buffer_percent(25)
+            .build();
+
+        let mut tx_request = TransactionRequest::default();
+        options.apply(&provider, &mut tx_request).await.unwrap();
+
+        assert_eq!(tx_request.gas, Some(125_000));
+        assert_eq!(tx_request.max_fee_per_gas, None);
+        assert_eq!(tx_request.nonce, None);
+    }
+}
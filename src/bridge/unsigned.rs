@@ -0,0 +1,281 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Unsigned transactions for external signers and multisigs.
+//!
+//! The bridge normally signs and sends through its own providers. When the
+//! key lives elsewhere, such as a Safe multisig or an HSM-backed signing
+//! service, use the `prepare_*` methods instead. Each returns a
+//! transaction request with calldata, chain id, gas limit and EIP-1559
+//! fees filled in, and sends nothing.
+//!
+//! The nonce is left unset because it belongs to the signer: a Safe orders
+//! its transactions by its own nonce, not by the EOA that executes them.
+//!
+//! Once the signed burn is mined, hand its hash back to the bridge to
+//! continue:
+//!
+//! ```rust,ignore
+//! if let Some(approval) = bridge.prepare_approval(usdc, safe, amount).await? {
+//!     let approval_tx = signer.submit(approval).await?;
+//!     bridge.wait_for_approval_receipt(approval_tx).await?;
+//! }
+//! let burn_tx = signer.submit(bridge.prepare_burn(amount, safe, usdc).await?).await?;
+//!
+//! // Either mint from the bridge's destination provider...
+//! let result = bridge.complete_transfer(burn_tx, relayer).await?;
+//! // ...or have the external signer submit the mint too.
+//! if let Some(mint) = bridge.prepare_mint_for_burn(burn_tx, safe).await? {
+//!     signer.submit(mint).await?;
+//! }
+//! ```
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{Address, TxHash, U256};
+use alloy_provider::Provider;
+use tracing::info;
+
+use super::cctp::Cctp;
+use super::receipt::{confirm_transaction, ReceiptSummary, TxKind};
+use super::simulation::estimate_gas;
+use super::transfer::default_polling_config;
+use super::v2::{CctpV2, MintResult};
+use crate::contracts::erc20::Erc20Contract;
+use crate::error::Result;
+use crate::protocol::AttestationBytes;
+
+/// Fills in chain id, gas limit and EIP-1559 fees without signing.
+async fn prepare_transaction<P: Provider<N>, N: Network>(
+    provider: &P,
+    mut tx_request: N::TransactionRequest,
+    kind: &'static str,
+) -> Result<N::TransactionRequest> {
+    let chain_id = provider.get_chain_id().await?;
+    let gas = estimate_gas(provider, tx_request.clone(), kind).await?;
+    let fees = provider.estimate_eip1559_fees().await?;
+
+    tx_request.set_chain_id(chain_id);
+    tx_request.set_gas_limit(gas);
+    tx_request.set_max_fee_per_gas(fees.max_fee_per_gas);
+    tx_request.set_max_priority_fee_per_gas(fees.max_priority_fee_per_gas);
+
+    info!(
+        kind = kind,
+        chain_id = chain_id,
+        gas = gas,
+        max_fee_per_gas = fees.max_fee_per_gas,
+        event = "unsigned_transaction_prepared"
+    );
+
+    Ok(tx_request)
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Build the approval [`Self::ensure_approval`] would send, if one is needed
+    ///
+    /// # Returns
+    ///
+    /// `None` if the current allowance already covers `amount`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::SimulationReverted`](crate::CctpError::SimulationReverted)
+    /// if gas estimation reverts.
+    pub async fn prepare_approval(
+        &self,
+        token_address: Address,
+        owner: Address,
+        amount: U256,
+    ) -> Result<Option<SN::TransactionRequest>> {
+        if self.get_allowance(token_address, owner).await? >= amount {
+            return Ok(None);
+        }
+
+        let spender = self.token_messenger_v2_contract()?;
+        let erc20 = Erc20Contract::new(token_address, self.source_provider().clone());
+        let tx_request = erc20.approve_transaction(owner, spender, amount);
+
+        prepare_transaction(self.source_provider(), tx_request, "approval")
+            .await
+            .map(Some)
+    }
+
+    /// Build the burn [`Self::burn`] would send, without sending it
+    ///
+    /// Gas is estimated against the current state, so the allowance must
+    /// already be in place. Submit and confirm the approval from
+    /// [`Self::prepare_approval`] first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::SimulationReverted`](crate::CctpError::SimulationReverted)
+    /// if the burn would revert.
+    pub async fn prepare_burn(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let tx_request = self.burn_transaction_request(amount, from, token_address)?;
+        prepare_transaction(self.source_provider(), tx_request, "burn").await
+    }
+
+    /// Build the mint [`Self::mint`] would send, without sending it
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::SimulationReverted`](crate::CctpError::SimulationReverted)
+    /// if the mint would revert, for example because the message was already
+    /// received.
+    pub async fn prepare_mint(
+        &self,
+        message_bytes: &[u8],
+        attestation: &[u8],
+        from: Address,
+    ) -> Result<DN::TransactionRequest> {
+        let tx_request = self.mint_transaction_request(message_bytes, attestation, from)?;
+        prepare_transaction(self.destination_provider(), tx_request, "mint").await
+    }
+
+    /// Wait for an externally signed approval and check that it succeeded
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::ApprovalReverted`](crate::CctpError::ApprovalReverted)
+    /// if the approval was mined but failed.
+    pub async fn wait_for_approval_receipt(&self, approval_tx: TxHash) -> Result<ReceiptSummary> {
+        confirm_transaction(
+            self.source_provider(),
+            approval_tx,
+            self.required_confirmations(),
+            TxKind::Approval,
+        )
+        .await
+    }
+
+    /// Continue from an externally signed burn and build the mint to sign
+    ///
+    /// Waits for the burn receipt and Circle's attestation, then returns the
+    /// prepared `receiveMessage` for the external signer.
+    ///
+    /// # Returns
+    ///
+    /// `None` if a relayer already minted the message
+    pub async fn prepare_mint_for_burn(
+        &self,
+        burn_tx: TxHash,
+        from: Address,
+    ) -> Result<Option<DN::TransactionRequest>> {
+        let (message, attestation) = self.attestation_for_burn(burn_tx).await?;
+        if self.is_message_received(&message).await? {
+            info!(
+                burn_tx = %burn_tx,
+                version = "v2",
+                event = "mint_not_needed_already_relayed"
+            );
+            return Ok(None);
+        }
+
+        self.prepare_mint(&message, &attestation, from)
+            .await
+            .map(Some)
+    }
+
+    /// Continue from an externally signed burn and mint from the bridge
+    ///
+    /// Waits for the burn receipt and Circle's attestation, then mints with
+    /// [`Self::mint_if_needed`] through the destination provider. Use this
+    /// when only the source-chain key is external. For a persisted version,
+    /// see [`Transfer::from_burn`](super::Transfer::from_burn).
+    pub async fn complete_transfer(&self, burn_tx: TxHash, from: Address) -> Result<MintResult> {
+        let (message, attestation) = self.attestation_for_burn(burn_tx).await?;
+        let result = self.mint_if_needed(message, attestation, from).await?;
+        if let (MintResult::Minted(mint_tx), Some(_)) = (&result, self.confirmations()) {
+            self.wait_for_mint_receipt(*mint_tx).await?;
+        }
+        Ok(result)
+    }
+
+    async fn attestation_for_burn(&self, burn_tx: TxHash) -> Result<(Vec<u8>, AttestationBytes)> {
+        self.wait_for_burn_receipt(burn_tx).await?;
+        self.get_attestation(burn_tx, default_polling_config(self))
+            .await
+    }
+}
+
+impl<SP, DP, SN, DN> Cctp<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Build the burn [`Self::burn_with_caller`] would send, without sending it
+    pub async fn prepare_burn_with_caller(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        destination_caller: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let tx_request = self.burn_with_caller_transaction_request(
+            amount,
+            from,
+            token_address,
+            destination_caller,
+        )?;
+        prepare_transaction(self.source_provider(), tx_request, "burn").await
+    }
+
+    /// Build the replacement [`Self::replace_deposit_for_burn`] would send,
+    /// without sending it
+    pub async fn prepare_replace_deposit_for_burn(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_destination_caller: Address,
+        new_mint_recipient: Address,
+        from: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let tx_request = self.replace_deposit_for_burn_transaction_request(
+            original_message,
+            original_attestation,
+            new_destination_caller,
+            new_mint_recipient,
+            from,
+        )?;
+        prepare_transaction(
+            self.source_provider(),
+            tx_request,
+            "replace_deposit_for_burn",
+        )
+        .await
+    }
+
+    /// Build the replacement [`Self::replace_message`] would send, without
+    /// sending it
+    pub async fn prepare_replace_message(
+        &self,
+        original_message: Vec<u8>,
+        original_attestation: AttestationBytes,
+        new_message_body: Vec<u8>,
+        new_destination_caller: Address,
+        from: Address,
+    ) -> Result<SN::TransactionRequest> {
+        let tx_request = self.replace_message_transaction_request(
+            original_message,
+            original_attestation,
+            new_message_body,
+            new_destination_caller,
+            from,
+        )?;
+        prepare_transaction(self.source_provider(), tx_request, "replace_message").await
+    }
+}
@@ -0,0 +1,617 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! ERC-4337 user operations for burns from smart accounts.
+//!
+//! [`CctpV2::burn_user_operation`] wraps the approval and the burn that
+//! [`CctpV2::burn`] would send into one EntryPoint v0.7 [`UserOperation`].
+//! The account's owner signs [`UserOperation::hash`], and a [`Bundler`]
+//! submits it:
+//!
+//! ```rust,ignore
+//! let nonce = bridge.user_operation_nonce(account).await?;
+//! let mut user_op = bridge.burn_user_operation(amount, account, usdc, nonce)?;
+//! user_op.call_gas_limit = 200_000;
+//! // ...fill in the remaining gas fields, then sign:
+//! user_op.signature = owner.sign_hash(&bridge.user_operation_hash(&user_op)).await?.as_bytes().into();
+//! let user_op_hash = bridge.submit_user_operation(&bundler, &user_op).await?;
+//! ```
+//!
+//! The call data targets `executeBatch(address[],uint256[],bytes[])` as
+//! implemented by the reference `SimpleAccount` and most accounts derived
+//! from it. Accounts with a different execute interface can encode the calls
+//! themselves and use [`UserOperation::new`].
+
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{address, keccak256, Address, Bytes, B256, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::{sol, SolCall, SolValue};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::Url;
+
+use super::v2::CctpV2;
+use crate::error::{CctpError, Result};
+
+/// The canonical ERC-4337 EntryPoint v0.7 deployment.
+pub const ENTRY_POINT_V07: Address = address!("0000000071727De22E5E9d8BAf0edAc6f37da032");
+
+/// An ERC-4337 v0.7 user operation, in the unpacked form bundlers accept.
+///
+/// Gas fields default to zero; fill them in, or ask the bundler with
+/// `eth_estimateUserOperationGas`, before signing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperation {
+    /// The smart account sending the operation
+    pub sender: Address,
+    /// Anti-replay nonce, as returned by the EntryPoint's `getNonce`
+    pub nonce: U256,
+    /// Factory deploying the account, for its first operation only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<Address>,
+    /// Calldata passed to the factory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_data: Option<Bytes>,
+    /// Calldata the EntryPoint sends to the account
+    pub call_data: Bytes,
+    /// Gas for the account's execution phase
+    #[serde(with = "quantity")]
+    pub call_gas_limit: u128,
+    /// Gas for the account's validation phase
+    #[serde(with = "quantity")]
+    pub verification_gas_limit: u128,
+    /// Gas paid to the bundler for overhead not metered on-chain
+    #[serde(with = "quantity")]
+    pub pre_verification_gas: u128,
+    /// EIP-1559 max fee per gas
+    #[serde(with = "quantity")]
+    pub max_fee_per_gas: u128,
+    /// EIP-1559 max priority fee per gas
+    #[serde(with = "quantity")]
+    pub max_priority_fee_per_gas: u128,
+    /// Paymaster sponsoring the operation, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster: Option<Address>,
+    /// Gas for the paymaster's validation phase
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "quantity::option"
+    )]
+    pub paymaster_verification_gas_limit: Option<u128>,
+    /// Gas for the paymaster's `postOp`
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "quantity::option"
+    )]
+    pub paymaster_post_op_gas_limit: Option<u128>,
+    /// Data passed to the paymaster
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_data: Option<Bytes>,
+    /// The account owner's signature over [`Self::hash`]
+    pub signature: Bytes,
+}
+
+impl UserOperation {
+    /// Create an unsigned operation with zero gas fields
+    pub fn new(sender: Address, nonce: U256, call_data: Bytes) -> Self {
+        Self {
+            sender,
+            nonce,
+            call_data,
+            ..Default::default()
+        }
+    }
+
+    /// Pack the operation into the on-chain `PackedUserOperation` layout
+    pub fn pack(&self) -> PackedUserOperation {
+        let init_code = match self.factory {
+            Some(factory) => {
+                let factory_data = self.factory_data.as_ref().map_or(&[][..], |data| &data[..]);
+                [factory.as_slice(), factory_data].concat().into()
+            }
+            None => Bytes::new(),
+        };
+
+        let paymaster_and_data = match self.paymaster {
+            Some(paymaster) => [
+                paymaster.as_slice(),
+                &self
+                    .paymaster_verification_gas_limit
+                    .unwrap_or_default()
+                    .to_be_bytes(),
+                &self
+                    .paymaster_post_op_gas_limit
+                    .unwrap_or_default()
+                    .to_be_bytes(),
+                self.paymaster_data
+                    .as_ref()
+                    .map_or(&[][..], |data| &data[..]),
+            ]
+            .concat()
+            .into(),
+            None => Bytes::new(),
+        };
+
+        PackedUserOperation {
+            sender: self.sender,
+            nonce: self.nonce,
+            initCode: init_code,
+            callData: self.call_data.clone(),
+            accountGasLimits: pack_u128_pair(self.verification_gas_limit, self.call_gas_limit),
+            preVerificationGas: U256::from(self.pre_verification_gas),
+            gasFees: pack_u128_pair(self.max_priority_fee_per_gas, self.max_fee_per_gas),
+            paymasterAndData: paymaster_and_data,
+            signature: self.signature.clone(),
+        }
+    }
+
+    /// The hash the account owner signs, as computed by `EntryPoint.getUserOpHash`
+    ///
+    /// The signature field is not covered by the hash.
+    pub fn hash(&self, entry_point: Address, chain_id: u64) -> B256 {
+        let packed = self.pack();
+        let encoded = (
+            packed.sender,
+            packed.nonce,
+            keccak256(&packed.initCode),
+            keccak256(&packed.callData),
+            packed.accountGasLimits,
+            packed.preVerificationGas,
+            packed.gasFees,
+            keccak256(&packed.paymasterAndData),
+        )
+            .abi_encode_params();
+
+        keccak256((keccak256(encoded), entry_point, U256::from(chain_id)).abi_encode_params())
+    }
+}
+
+/// Packs two 128-bit values into one word, `high` first.
+fn pack_u128_pair(high: u128, low: u128) -> B256 {
+    let mut word = [0u8; 32];
+    word[..16].copy_from_slice(&high.to_be_bytes());
+    word[16..].copy_from_slice(&low.to_be_bytes());
+    B256::from(word)
+}
+
+/// Encode calls as `SimpleAccount.executeBatch(address[],uint256[],bytes[])`.
+///
+/// Accepts the requests built by the contract wrappers for any network, such as
+/// [`Erc20Contract::approve_transaction`](crate::Erc20Contract::approve_transaction)
+/// and [`TokenMessengerV2Contract::deposit_for_burn_with_hooks_transaction`](crate::TokenMessengerV2Contract::deposit_for_burn_with_hooks_transaction).
+///
+/// # Errors
+///
+/// Returns [`CctpError::InvalidConfig`] if a request has no `to` address.
+pub fn simple_account_execute_batch<T: TransactionBuilder>(calls: &[T]) -> Result<Bytes> {
+    let mut dest = Vec::with_capacity(calls.len());
+    let mut value = Vec::with_capacity(calls.len());
+    let mut func = Vec::with_capacity(calls.len());
+
+    for call in calls {
+        let to = call.to().ok_or_else(|| {
+            CctpError::InvalidConfig("user operation calls cannot create contracts".to_string())
+        })?;
+        dest.push(to);
+        value.push(call.value().unwrap_or_default());
+        func.push(call.input().cloned().unwrap_or_default());
+    }
+
+    Ok(SimpleAccount::executeBatchCall { dest, value, func }
+        .abi_encode()
+        .into())
+}
+
+/// Submits user operations to an ERC-4337 bundler.
+///
+/// [`HttpBundler`] speaks the standard JSON-RPC API. Implement this trait to
+/// route operations elsewhere, or to stand in for a bundler in tests.
+#[async_trait]
+pub trait Bundler: Send + Sync {
+    /// Send `user_operation` for `entry_point` and return its user operation hash
+    async fn send_user_operation(
+        &self,
+        user_operation: &UserOperation,
+        entry_point: Address,
+    ) -> Result<B256>;
+}
+
+/// A [`Bundler`] reached over JSON-RPC (`eth_sendUserOperation`).
+#[derive(Debug, Clone)]
+pub struct HttpBundler {
+    client: Client,
+    url: Url,
+}
+
+impl HttpBundler {
+    /// Create a client for the bundler at `url`
+    pub fn new(url: Url) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl Bundler for HttpBundler {
+    async fn send_user_operation(
+        &self,
+        user_operation: &UserOperation,
+        entry_point: Address,
+    ) -> Result<B256> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_sendUserOperation",
+            "params": [user_operation, entry_point],
+        });
+
+        let response: JsonRpcResponse = self
+            .client
+            .post(self.url.as_str())
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match response {
+            JsonRpcResponse {
+                error: Some(error), ..
+            } => Err(CctpError::Bundler {
+                code: error.code,
+                message: error.message,
+            }),
+            JsonRpcResponse {
+                result: Some(hash), ..
+            } => Ok(hash),
+            JsonRpcResponse { .. } => Err(CctpError::Bundler {
+                code: 0,
+                message: "response has neither result nor error".to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    result: Option<B256>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Build a user operation that approves and burns from a smart account
+    ///
+    /// The approval covers exactly `amount`, and the burn is the
+    /// `depositForBurn` variant [`Self::burn`] would send, including hooks.
+    /// Gas fields and the signature are left for the caller to fill in.
+    pub fn burn_user_operation(
+        &self,
+        amount: U256,
+        account: Address,
+        token_address: Address,
+        nonce: U256,
+    ) -> Result<UserOperation> {
+        let calls = self.approve_and_burn_requests(amount, account, token_address)?;
+        let call_data = simple_account_execute_batch(&calls)?;
+        Ok(UserOperation::new(account, nonce, call_data))
+    }
+
+    /// Next EntryPoint v0.7 nonce of `account` (key 0) on the source chain
+    pub async fn user_operation_nonce(&self, account: Address) -> Result<U256> {
+        let entry_point = EntryPoint::new(ENTRY_POINT_V07, self.source_provider().clone());
+        Ok(entry_point
+            .getNonce(account, alloy_primitives::aliases::U192::ZERO)
+            .call()
+            .await?)
+    }
+
+    /// The hash the account owner signs for `user_operation` on the source chain
+    pub fn user_operation_hash(&self, user_operation: &UserOperation) -> B256 {
+        user_operation.hash(ENTRY_POINT_V07, (*self.source_chain()).into())
+    }
+
+    /// Submit a signed user operation to `bundler` for EntryPoint v0.7
+    ///
+    /// Returns the user operation hash reported by the bundler. A hash that
+    /// differs from [`Self::user_operation_hash`] is logged, since it usually
+    /// means the bundler serves a different chain.
+    pub async fn submit_user_operation<B: Bundler + ?Sized>(
+        &self,
+        bundler: &B,
+        user_operation: &UserOperation,
+    ) -> Result<B256> {
+        let expected = self.user_operation_hash(user_operation);
+        let user_op_hash = bundler
+            .send_user_operation(user_operation, ENTRY_POINT_V07)
+            .await?;
+
+        if user_op_hash != expected {
+            warn!(
+                user_op_hash = %user_op_hash,
+                expected = %expected,
+                version = "v2",
+                event = "user_operation_hash_mismatch"
+            );
+        }
+
+        info!(
+            user_op_hash = %user_op_hash,
+            sender = %user_operation.sender,
+            nonce = %user_operation.nonce,
+            version = "v2",
+            event = "user_operation_submitted"
+        );
+
+        Ok(user_op_hash)
+    }
+}
+
+/// Serializes gas values as hex quantities, as bundlers expect.
+mod quantity {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &u128,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<u128, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let digits = s
+            .strip_prefix("0x")
+            .ok_or_else(|| de::Error::custom(format!("quantity {s:?} is missing the 0x prefix")))?;
+        u128::from_str_radix(digits, 16).map_err(de::Error::custom)
+    }
+
+    pub(super) mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub(in super::super) fn serialize<S: Serializer>(
+            value: &Option<u128>,
+            serializer: S,
+        ) -> std::result::Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(in super::super) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> std::result::Result<Option<u128>, D::Error> {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(with = "super")] u128);
+
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(value)| value))
+        }
+    }
+}
+
+sol! {
+    /// The EntryPoint v0.7 `PackedUserOperation` struct.
+    #[derive(Debug, PartialEq, Eq)]
+    struct PackedUserOperation {
+        address sender;
+        uint256 nonce;
+        bytes initCode;
+        bytes callData;
+        bytes32 accountGasLimits;
+        uint256 preVerificationGas;
+        bytes32 gasFees;
+        bytes paymasterAndData;
+        bytes signature;
+    }
+
+    #[allow(missing_docs)]
+    interface SimpleAccount {
+        function executeBatch(address[] dest, uint256[] value, bytes[] func) external;
+    }
+
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface EntryPoint {
+        function getNonce(address sender, uint192 key) external view returns (uint256 nonce);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::offline_bridge;
+    use alloy_primitives::{b256, bytes, hex};
+    use rstest::rstest;
+    use std::sync::Mutex;
+
+    /// Records submitted operations and answers with the locally computed hash.
+    #[derive(Default)]
+    struct LocalBundler {
+        submitted: Mutex<Vec<(UserOperation, Address)>>,
+    }
+
+    #[async_trait]
+    impl Bundler for LocalBundler {
+        async fn send_user_operation(
+            &self,
+            user_operation: &UserOperation,
+            entry_point: Address,
+        ) -> Result<B256> {
+            self.submitted
+                .lock()
+                .unwrap()
+                .push((user_operation.clone(), entry_point));
+            Ok(user_operation.hash(entry_point, 1))
+        }
+    }
+
+    #[test]
+    fn test_pack_layout() {
+        let user_op = UserOperation {
+            sender: Address::repeat_byte(0x11),
+            factory: Some(Address::repeat_byte(0x22)),
+            factory_data: Some(Bytes::from(vec![0xde, 0xad])),
+            call_gas_limit: 2,
+            verification_gas_limit: 1,
+            max_fee_per_gas: 4,
+            max_priority_fee_per_gas: 3,
+            paymaster: Some(Address::repeat_byte(0x33)),
+            paymaster_verification_gas_limit: Some(5),
+            paymaster_post_op_gas_limit: Some(6),
+            paymaster_data: Some(Bytes::from(vec![0xbe, 0xef])),
+            ..Default::default()
+        };
+
+        let packed = user_op.pack();
+        assert_eq!(packed.initCode.len(), 22);
+        assert_eq!(&packed.initCode[20..], &[0xde, 0xad]);
+        assert_eq!(packed.accountGasLimits[15], 1);
+        assert_eq!(packed.accountGasLimits[31], 2);
+        assert_eq!(packed.gasFees[15], 3);
+        assert_eq!(packed.gasFees[31], 4);
+        assert_eq!(packed.paymasterAndData.len(), 20 + 16 + 16 + 2);
+        assert_eq!(packed.paymasterAndData[35], 5);
+        assert_eq!(packed.paymasterAndData[51], 6);
+    }
+
+    #[test]
+    fn test_hash_ignores_signature_and_binds_chain() {
+        let user_op = UserOperation::new(Address::repeat_byte(0x11), U256::from(7), Bytes::new());
+        let signed = UserOperation {
+            signature: Bytes::from(vec![1; 65]),
+            ..user_op.clone()
+        };
+
+        assert_eq!(
+            user_op.hash(ENTRY_POINT_V07, 1),
+            signed.hash(ENTRY_POINT_V07, 1)
+        );
+        assert_ne!(
+            user_op.hash(ENTRY_POINT_V07, 1),
+            user_op.hash(ENTRY_POINT_V07, 8453)
+        );
+    }
+
+    /// Expected hashes computed with a standalone Keccak-256 and ABI encoder
+    /// following `EntryPoint.getUserOpHash` v0.7, independent of this crate
+    #[rstest]
+    #[case::minimal(
+        UserOperation {
+            call_gas_limit: 6_942_069,
+            verification_gas_limit: 6_942_069,
+            pre_verification_gas: 6_942_069,
+            max_fee_per_gas: 69_420,
+            max_priority_fee_per_gas: 69,
+            ..UserOperation::new(
+                address!("1234567890123456789012345678901234567890"),
+                U256::ZERO,
+                Bytes::new(),
+            )
+        },
+        1,
+        b256!("968b74e583496d04da0d481d8ce43aa9140bff195059965ed48a635fb53089db")
+    )]
+    #[case::factory_and_paymaster(
+        UserOperation {
+            factory: Some(address!("9406Cc6185a346906296840746125a0E44976454")),
+            factory_data: Some(
+                bytes!("5fbfb9cf0000000000000000000000000000000000000000000000000000000000000000")
+            ),
+            call_gas_limit: 100_000,
+            verification_gas_limit: 150_000,
+            pre_verification_gas: 45_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            paymaster: Some(address!("0000000000000039cd5e8aE05257CE51C473ddd1")),
+            paymaster_verification_gas_limit: Some(100_000),
+            paymaster_post_op_gas_limit: Some(50_000),
+            paymaster_data: Some(bytes!("c0ffee")),
+            signature: Bytes::from(vec![0xff; 65]),
+            ..UserOperation::new(
+                address!("1234567890123456789012345678901234567890"),
+                (U256::from(5) << 64) | U256::from(3),
+                bytes!("b61d27f6"),
+            )
+        },
+        8453,
+        b256!("f682a7c5f614f4af247e53dd6e2e504e668775fb192a444aedc6de00ce3c1c0c")
+    )]
+    fn test_hash_known_answers(
+        #[case] user_op: UserOperation,
+        #[case] chain_id: u64,
+        #[case] expected: B256,
+    ) {
+        assert_eq!(user_op.hash(ENTRY_POINT_V07, chain_id), expected);
+    }
+
+    #[test]
+    fn test_user_operation_json_uses_hex_quantities() {
+        let user_op = UserOperation {
+            call_gas_limit: 200_000,
+            ..UserOperation::new(Address::repeat_byte(0x11), U256::from(1), Bytes::new())
+        };
+
+        let json = serde_json::to_value(&user_op).unwrap();
+        assert_eq!(json["callGasLimit"], "0x30d40");
+        assert_eq!(json["nonce"], "0x1");
+        assert!(json.get("paymaster").is_none());
+        assert_eq!(
+            serde_json::from_value::<UserOperation>(json).unwrap(),
+            user_op
+        );
+    }
+
+    #[tokio::test]
+    async fn test_burn_user_operation_through_local_bundler() {
+        let bridge = offline_bridge();
+        let account = Address::repeat_byte(0xaa);
+        let usdc = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let amount = U256::from(1_000_000);
+
+        let user_op = bridge
+            .burn_user_operation(amount, account, usdc, U256::ZERO)
+            .unwrap();
+        assert_eq!(user_op.sender, account);
+
+        let call = SimpleAccount::executeBatchCall::abi_decode(&user_op.call_data).unwrap();
+        assert_eq!(
+            call.dest,
+            vec![usdc, bridge.token_messenger_v2_contract().unwrap()]
+        );
+        assert_eq!(call.func[0][..4], hex!("095ea7b3")); // approve(address,uint256)
+
+        let bundler = LocalBundler::default();
+        let user_op_hash = bridge
+            .submit_user_operation(&bundler, &user_op)
+            .await
+            .unwrap();
+
+        assert_eq!(user_op_hash, bridge.user_operation_hash(&user_op));
+        assert_eq!(
+            bundler.submitted.lock().unwrap().as_slice(),
+            &[(user_op, ENTRY_POINT_V07)]
+        );
+    }
+}
@@ -3,17 +3,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::{AttestationFailureKind, CctpError, Result};
-use crate::protocol::{AttestationBytes, FinalityThreshold};
-use crate::{spans, AttestationStatus, CctpV2 as CctpV2Trait, DomainId, V2AttestationResponse};
-use alloy_chains::NamedChain;
-use alloy_network::Ethereum;
-use alloy_primitives::{hex, Address, Bytes, FixedBytes, TxHash, U256};
-use alloy_provider::Provider;
-use alloy_sol_types::SolEvent;
+use crate::protocol::{
+    usdc_eip712_domain, AttestationBytes, FinalityThreshold, MessageHeader, Permit, PermitRequest,
+    USDC_EIP712_VERSION,
+};
+use crate::{
+    spans, AttestationStatus, CctpEnvironment, CctpV2 as CctpV2Trait, ChainRegistry, DomainId,
+    V2AttestationResponse,
+};
+use alloy_chains::Chain;
+use alloy_network::{Ethereum, Network, TransactionBuilder};
+use alloy_primitives::{hex, Address, Bytes, FixedBytes, Signature, TxHash, B256, U256};
+use alloy_provider::{PendingTransactionBuilder, Provider, WalletProvider};
+use alloy_sol_types::{Eip712Domain, SolEvent};
 use async_trait::async_trait;
 use bon::Builder;
 use reqwest::{Client, Response};
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, error, info};
@@ -34,10 +42,23 @@ pub enum MintResult {
 }
 
 use super::bridge_trait::CctpBridge;
-use super::config::{PollingConfig, IRIS_API, IRIS_API_SANDBOX, MESSAGES_PATH_V2};
+use super::config::{PollingConfig, MESSAGES_PATH_V2};
+use super::nonce::NonceManager;
+use super::receipt::{confirm_receipt, confirm_transaction, ReceiptSummary, TxKind};
+use super::replacement::ReplacementPolicy;
+use super::simulation::simulate_call;
+use super::tx_options::TxOptions;
 use crate::contracts::erc20::Erc20Contract;
 use crate::contracts::message_transmitter::MessageTransmitter::MessageSent;
-use crate::contracts::v2::{MessageTransmitterV2Contract, TokenMessengerV2Contract};
+use crate::contracts::v2::{
+    MessageTransmitterV2Contract, TokenMessengerV2Contract, TokenMinterV2Contract,
+};
+
+/// Gas limit of a burn sent by [`CctpV2::burn_with_permit`] when the bridge's
+/// `tx_options` set none
+///
+/// Covers `depositForBurn` and `depositForBurnWithHook` with short hook data.
+pub const PERMIT_BURN_GAS_LIMIT: u64 = 300_000;
 
 /// CCTP v2 bridge implementation
 ///
@@ -85,11 +106,26 @@ use crate::contracts::v2::{MessageTransmitterV2Contract, TokenMessengerV2Contrac
 /// # }
 /// ```
 #[derive(Builder, Clone, Debug)]
-pub struct CctpV2<P: Provider<Ethereum> + Clone> {
-    source_provider: P,
-    destination_provider: P,
-    source_chain: NamedChain,
-    destination_chain: NamedChain,
+pub struct CctpV2<
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone = SP,
+    SN: Network = Ethereum,
+    DN: Network = SN,
+> {
+    source_provider: SP,
+    destination_provider: DP,
+
+    /// Chain USDC is burned on
+    ///
+    /// A `NamedChain`, or a chain id for chains `alloy_chains` does not
+    /// name, such as Codex or a chain described in the registry.
+    #[builder(into)]
+    source_chain: Chain,
+
+    /// Chain USDC is minted on, given like `source_chain`
+    #[builder(into)]
+    destination_chain: Chain,
+
     recipient: Address,
 
     /// Enable fast transfer (sub-30 second settlement)
@@ -101,51 +137,173 @@ pub struct CctpV2<P: Provider<Ethereum> + Clone> {
 
     /// Maximum fee willing to pay for fast transfer (in USDC atomic units)
     max_fee: Option<U256>,
+
+    /// Confirmation depth to wait for on burn and mint receipts
+    ///
+    /// When set, [`Self::transfer`] waits for the burn receipt to reach this
+    /// depth before polling Iris, and checks the mint receipt the same way.
+    /// Approvals sent by [`Self::ensure_approval`] always wait for at least
+    /// one confirmation.
+    confirmations: Option<u64>,
+
+    /// Address the source provider signs with
+    ///
+    /// When set, approvals and burns sent through the source provider must
+    /// come from this address, so a `from` that does not match the
+    /// provider's wallet fails before anything is sent, and
+    /// [`Self::burn_from_signer`] and [`Self::transfer_from_signer`] send
+    /// from it. [`Self::with_source_wallet`] fills it from, or checks it
+    /// against, the source provider's wallet.
+    source_signer: Option<Address>,
+
+    /// Address the destination provider signs with
+    ///
+    /// When set, mints must come from this address, and [`Self::transfer`],
+    /// [`Self::transfer_chunked`] and [`Transfer`](super::Transfer) mint from
+    /// it instead of the burn's `from`. Use this when a relayer key on the
+    /// destination chain completes transfers burned by a different key.
+    /// [`Self::with_destination_wallet`] fills it from, or checks it
+    /// against, the destination provider's wallet.
+    destination_signer: Option<Address>,
+
+    /// Default overrides for every burn and approval
+    ///
+    /// Applied by [`Self::burn`] and [`Self::approve`], and so by
+    /// [`Self::transfer`] and [`Transfer`](super::Transfer). The
+    /// `*_with_options` methods take explicit options instead.
+    tx_options: Option<TxOptions<SN>>,
+
+    /// Default overrides for every mint
+    ///
+    /// The destination-chain counterpart of `tx_options`, applied by
+    /// [`Self::mint`]. Kept apart because the two chains can be different
+    /// networks.
+    destination_tx_options: Option<TxOptions<DN>>,
+
+    /// Re-send stuck burns and mints with bumped fees
+    ///
+    /// When set, [`Self::transfer`], [`Transfer`](super::Transfer) and
+    /// [`Self::transfer_chunked`] watch each burn and mint they send and
+    /// report the hash that was actually included. See
+    /// [`Self::replace_stuck_burn`].
+    replacement_policy: Option<ReplacementPolicy>,
+
+    /// Assign nonces per sender instead of leaving them to the providers
+    ///
+    /// Set this when burns, approvals or mints from one account are sent
+    /// concurrently. See [`NonceManager`].
+    nonce_manager: Option<NonceManager>,
+
+    /// Chain records to resolve the source and destination chains through
+    ///
+    /// Chains without a record fall back to the built-in tables. See
+    /// [`ChainRegistry`].
+    #[builder(into)]
+    registry: Option<Arc<ChainRegistry>>,
+
+    /// Environment to attest and mint through, instead of the source chain's
+    ///
+    /// Decides the Iris API URL. A [`CctpEnvironment::Custom`] environment
+    /// also replaces both chains' v2 contract addresses with its own, which
+    /// models staging deployments and forks served by a mock Iris.
+    environment: Option<CctpEnvironment>,
+
+    #[builder(skip)]
+    network: PhantomData<(SN, DN)>,
 }
 
-impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
     /// Returns the CCTP v2 API URL for the current environment
     pub fn api_url(&self) -> Url {
-        if self.source_chain.is_testnet() {
-            Url::parse(IRIS_API_SANDBOX).unwrap()
-        } else {
-            Url::parse(IRIS_API).unwrap()
+        self.environment().iris_api_url()
+    }
+
+    /// Returns the environment the bridge attests through
+    ///
+    /// The builder's `environment`, or else the source chain's, resolved
+    /// through the registry.
+    pub fn environment(&self) -> CctpEnvironment {
+        match &self.environment {
+            Some(environment) => environment.clone(),
+            None => self.source_config().environment(),
+        }
+    }
+
+    /// The v2 contracts of an explicitly set custom environment, as
+    /// (token messenger, message transmitter)
+    fn custom_contracts(&self) -> Option<(Address, Address)> {
+        match &self.environment {
+            Some(CctpEnvironment::Custom {
+                token_messenger,
+                message_transmitter,
+                ..
+            }) => Some((*token_messenger, *message_transmitter)),
+            _ => None,
         }
     }
 
     /// Returns the source chain
-    pub fn source_chain(&self) -> &NamedChain {
+    pub fn source_chain(&self) -> &Chain {
         &self.source_chain
     }
 
     /// Returns the destination chain
-    pub fn destination_chain(&self) -> &NamedChain {
+    pub fn destination_chain(&self) -> &Chain {
         &self.destination_chain
     }
 
+    /// Returns the source chain's configuration, resolved through the registry
+    fn source_config(&self) -> &dyn CctpV2Trait {
+        match &self.registry {
+            Some(registry) => registry.v2(&self.source_chain),
+            None => &self.source_chain,
+        }
+    }
+
+    /// Returns the destination chain's configuration, resolved through the
+    /// registry
+    fn destination_config(&self) -> &dyn CctpV2Trait {
+        match &self.registry {
+            Some(registry) => registry.v2(&self.destination_chain),
+            None => &self.destination_chain,
+        }
+    }
+
     /// Returns the destination domain id
     pub fn destination_domain_id(&self) -> Result<DomainId> {
-        self.destination_chain.cctp_v2_domain_id()
+        self.destination_config().cctp_v2_domain_id()
     }
 
     /// Returns the source provider
-    pub fn source_provider(&self) -> &P {
+    pub fn source_provider(&self) -> &SP {
         &self.source_provider
     }
 
     /// Returns the destination provider
-    pub fn destination_provider(&self) -> &P {
+    pub fn destination_provider(&self) -> &DP {
         &self.destination_provider
     }
 
     /// Returns the CCTP v2 token messenger contract address
     pub fn token_messenger_v2_contract(&self) -> Result<Address> {
-        self.source_chain.token_messenger_v2_address()
+        match self.custom_contracts() {
+            Some((token_messenger, _)) => Ok(token_messenger),
+            None => self.source_config().token_messenger_v2_address(),
+        }
     }
 
     /// Returns the CCTP v2 message transmitter contract address
     pub fn message_transmitter_v2_contract(&self) -> Result<Address> {
-        self.destination_chain.message_transmitter_v2_address()
+        match self.custom_contracts() {
+            Some((_, message_transmitter)) => Ok(message_transmitter),
+            None => self.destination_config().message_transmitter_v2_address(),
+        }
     }
 
     /// Returns the recipient address
@@ -168,6 +326,224 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         self.max_fee
     }
 
+    /// Returns the configured receipt confirmation depth, if any
+    pub fn confirmations(&self) -> Option<u64> {
+        self.confirmations
+    }
+
+    /// Returns the configured source-chain signer, if any
+    pub fn source_signer(&self) -> Option<Address> {
+        self.source_signer
+    }
+
+    /// Returns the configured destination-chain signer, if any
+    pub fn destination_signer(&self) -> Option<Address> {
+        self.destination_signer
+    }
+
+    /// Returns the default burn and approval overrides, if any
+    pub fn tx_options(&self) -> Option<&TxOptions<SN>> {
+        self.tx_options.as_ref()
+    }
+
+    /// Returns the default mint overrides, if any
+    pub fn destination_tx_options(&self) -> Option<&TxOptions<DN>> {
+        self.destination_tx_options.as_ref()
+    }
+
+    /// Returns the stuck-transaction replacement policy, if any
+    pub fn replacement_policy(&self) -> Option<ReplacementPolicy> {
+        self.replacement_policy
+    }
+
+    /// Returns the nonce manager, if any
+    pub fn nonce_manager(&self) -> Option<&NonceManager> {
+        self.nonce_manager.as_ref()
+    }
+
+    /// Returns the chain registry, if any
+    pub fn registry(&self) -> Option<&ChainRegistry> {
+        self.registry.as_deref()
+    }
+
+    /// Send from `from` on the source chain, through the nonce manager if set
+    pub(super) async fn send_source(
+        &self,
+        from: Address,
+        tx_request: SN::TransactionRequest,
+    ) -> Result<PendingTransactionBuilder<SN>> {
+        match &self.nonce_manager {
+            Some(manager) => {
+                manager
+                    .send(
+                        &self.source_provider,
+                        self.source_chain.id(),
+                        from,
+                        tx_request,
+                    )
+                    .await
+            }
+            None => Ok(self.source_provider.send_transaction(tx_request).await?),
+        }
+    }
+
+    /// Send from `from` on the destination chain, through the nonce manager
+    /// if set
+    pub(super) async fn send_destination(
+        &self,
+        from: Address,
+        tx_request: DN::TransactionRequest,
+    ) -> Result<PendingTransactionBuilder<DN>> {
+        match &self.nonce_manager {
+            Some(manager) => {
+                manager
+                    .send(
+                        &self.destination_provider,
+                        self.destination_chain.id(),
+                        from,
+                        tx_request,
+                    )
+                    .await
+            }
+            None => Ok(self
+                .destination_provider
+                .send_transaction(tx_request)
+                .await?),
+        }
+    }
+
+    /// The builder-level burn and approval overrides, or none
+    pub(super) fn default_tx_options(&self) -> TxOptions<SN> {
+        self.tx_options.clone().unwrap_or_default()
+    }
+
+    /// The builder-level mint overrides, or none
+    pub(super) fn default_destination_tx_options(&self) -> TxOptions<DN> {
+        self.destination_tx_options.clone().unwrap_or_default()
+    }
+
+    /// Sender of the mint for a transfer burned by `from`
+    pub(super) fn mint_sender(&self, from: Address) -> Address {
+        self.destination_signer.unwrap_or(from)
+    }
+
+    /// Rejects a source-chain `from` other than the configured source signer
+    pub(super) fn check_source_sender(&self, from: Address) -> Result<()> {
+        check_sender("source", self.source_signer, from)
+    }
+
+    /// Rejects a destination-chain `from` other than the configured destination signer
+    pub(super) fn check_destination_sender(&self, from: Address) -> Result<()> {
+        check_sender("destination", self.destination_signer, from)
+    }
+
+    /// The configured source signer, required by the `*_from_signer` methods
+    fn required_source_signer(&self) -> Result<Address> {
+        self.source_signer.ok_or_else(|| {
+            CctpError::InvalidConfig(
+                "no source signer is configured; set source_signer or call with_source_wallet"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// The configured destination signer, required by [`Self::mint_from_signer`]
+    fn required_destination_signer(&self) -> Result<Address> {
+        self.destination_signer.ok_or_else(|| {
+            CctpError::InvalidConfig(
+                "no destination signer is configured; set destination_signer or call \
+                 with_destination_wallet"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Confirmation depth used when waiting for receipts (at least one block)
+    pub(super) fn required_confirmations(&self) -> u64 {
+        self.confirmations.unwrap_or(1).max(1)
+    }
+
+    /// Check the chain pair and that each provider is on its declared chain
+    ///
+    /// Rejects chains without CCTP v2 support, a source equal to the
+    /// destination, chains in different environments, a mainnet or testnet
+    /// `environment` other than the chains' own and Fast Transfer from
+    /// a source that does not offer it, then asks each
+    /// provider for its `eth_chainId`. The builder's `try_build` runs this on
+    /// the new bridge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::UnsupportedChain`] for a chain without v2
+    /// support ([`CctpError::UnsupportedChainId`] if it has no `NamedChain`
+    /// variant), and [`CctpError::InvalidConfig`] for the other checks.
+    pub async fn validate(&self) -> Result<()> {
+        self.validate_chains()?;
+        check_provider_chain("source", &self.source_provider, self.source_chain).await?;
+        check_provider_chain(
+            "destination",
+            &self.destination_provider,
+            self.destination_chain,
+        )
+        .await
+    }
+
+    /// The provider-free part of [`Self::validate`]
+    fn validate_chains(&self) -> Result<()> {
+        for chain in [self.source_chain, self.destination_chain] {
+            let supported = match &self.registry {
+                Some(registry) => registry.v2(&chain).supports_cctp_v2(),
+                None => chain.supports_cctp_v2(),
+            };
+            if !supported {
+                return Err(match chain.named() {
+                    Some(named) => CctpError::UnsupportedChain(named),
+                    None => CctpError::UnsupportedChainId(chain.id()),
+                });
+            }
+        }
+
+        if self.source_chain == self.destination_chain {
+            return Err(CctpError::InvalidConfig(format!(
+                "source and destination are both {}",
+                self.source_chain
+            )));
+        }
+
+        let source_environment = self.source_config().environment();
+        let destination_environment = self.destination_config().environment();
+        if source_environment != destination_environment {
+            return Err(CctpError::InvalidConfig(format!(
+                "source {} is on {} but destination {} is on {}",
+                self.source_chain,
+                source_environment,
+                self.destination_chain,
+                destination_environment,
+            )));
+        }
+
+        // Only a custom environment may stand in for the chains' own
+        if let Some(environment) = &self.environment {
+            if !matches!(environment, CctpEnvironment::Custom { .. })
+                && *environment != source_environment
+            {
+                return Err(CctpError::InvalidConfig(format!(
+                    "environment is {} but {} is on {}",
+                    environment, self.source_chain, source_environment,
+                )));
+            }
+        }
+
+        if self.fast_transfer && !self.source_config().supports_fast_transfer()? {
+            return Err(CctpError::InvalidConfig(format!(
+                "source {} does not offer Fast Transfer",
+                self.source_chain
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Returns the finality threshold based on configuration
     pub fn finality_threshold(&self) -> FinalityThreshold {
         if self.fast_transfer {
@@ -205,7 +581,7 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             spans::get_message_sent_event(tx_hash, &self.source_chain, &self.destination_chain);
         let _guard = span.enter();
 
-        let tx_receipt = match self.source_provider.get_transaction_receipt(tx_hash).await {
+        let tx_logs = match super::receipt::transaction_logs(&self.source_provider, tx_hash).await {
             Ok(receipt) => receipt,
             Err(e) => {
                 spans::record_error_with_context(
@@ -221,13 +597,11 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             }
         };
 
-        if let Some(tx_receipt) = tx_receipt {
+        if let Some(tx_logs) = tx_logs {
             // Calculate the event topic by hashing the event signature
             let message_sent_topic = alloy_primitives::keccak256(b"MessageSent(bytes)");
 
-            let message_sent_log = tx_receipt
-                .inner
-                .logs()
+            let message_sent_log = tx_logs
                 .iter()
                 .find(|log| {
                     log.topics()
@@ -240,11 +614,11 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
                         "MessageSent event not found in transaction logs",
                         Some(&format!(
                             "Transaction contained {} logs but none matched MessageSent signature",
-                            tx_receipt.inner.logs().len()
+                            tx_logs.len()
                         )),
                     );
                     error!(
-                        available_logs = tx_receipt.inner.logs().len(),
+                        available_logs = tx_logs.len(),
                         event = "message_sent_event_not_found"
                     );
                     CctpError::MessageSentEventMissing { tx_hash }
@@ -544,6 +918,112 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         from: Address,
         token_address: Address,
     ) -> Result<TxHash> {
+        self.burn_with_options(amount, from, token_address, &self.default_tx_options())
+            .await
+    }
+
+    /// Burn USDC from the configured [`Self::source_signer`]
+    ///
+    /// Same as [`Self::burn`] with `from` set to the source signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if no source signer is configured.
+    pub async fn burn_from_signer(&self, amount: U256, token_address: Address) -> Result<TxHash> {
+        self.burn(amount, self.required_source_signer()?, token_address)
+            .await
+    }
+
+    /// Burn USDC with explicit gas, nonce, fee or access-list overrides
+    ///
+    /// Same as [`Self::burn`], but applies `options` instead of the bridge's
+    /// default [`tx_options`](Self::tx_options).
+    pub async fn burn_with_options(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+        options: &TxOptions<SN>,
+    ) -> Result<TxHash> {
+        self.check_source_sender(from)?;
+        let mut tx_request = self.burn_transaction_request(amount, from, token_address)?;
+        options
+            .apply(&self.source_provider, &mut tx_request)
+            .await?;
+
+        info!(
+            from = %from,
+            amount = %amount,
+            token_address = %token_address,
+            destination_domain = %self.destination_domain_id()?,
+            fast_transfer = self.fast_transfer,
+            has_hooks = self.hook_data.is_some(),
+            version = "v2",
+            event = "burn_transaction_initiated"
+        );
+
+        let pending_tx = self.send_source(from, tx_request).await?;
+        let tx_hash = *pending_tx.tx_hash();
+
+        info!(
+            tx_hash = %tx_hash,
+            version = "v2",
+            event = "burn_transaction_sent"
+        );
+
+        Ok(tx_hash)
+    }
+
+    /// Dry-run the burn with `eth_call` without sending it
+    ///
+    /// Simulates exactly the `depositForBurn` variant that [`Self::burn`] would
+    /// send, against the latest source-chain block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::SimulationReverted`] with a [`ContractRevert`](crate::ContractRevert)
+    /// such as `Denylisted`, `BurnLimitExceeded`, `InsufficientAllowance`,
+    /// `InsufficientBalance` or `Paused` if the burn would revert.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match bridge.simulate_burn(amount, from, usdc).await {
+    ///     Ok(()) => bridge.burn(amount, from, usdc).await?,
+    ///     Err(CctpError::SimulationReverted(reason)) => return Err(reject(reason.to_string())),
+    ///     Err(e) => return Err(e.into()),
+    /// };
+    /// ```
+    pub async fn simulate_burn(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<()> {
+        let tx_request = self.burn_transaction_request(amount, from, token_address)?;
+        simulate_call(&self.source_provider, tx_request, "burn").await
+    }
+
+    /// Builds an approval of exactly `amount` followed by the burn
+    pub(super) fn approve_and_burn_requests(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<[SN::TransactionRequest; 2]> {
+        let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
+        let approval = erc20.approve_transaction(from, self.token_messenger_v2_contract()?, amount);
+        let burn = self.burn_transaction_request(amount, from, token_address)?;
+        Ok([approval, burn])
+    }
+
+    /// Builds the `depositForBurn` variant selected by the bridge configuration
+    pub(super) fn burn_transaction_request(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<SN::TransactionRequest> {
         let token_messenger_address = self.token_messenger_v2_contract()?;
         let destination_domain = self.destination_domain_id()?;
 
@@ -582,27 +1062,7 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             )
         };
 
-        info!(
-            from = %from,
-            amount = %amount,
-            token_address = %token_address,
-            destination_domain = %destination_domain,
-            fast_transfer = self.fast_transfer,
-            has_hooks = self.hook_data.is_some(),
-            version = "v2",
-            event = "burn_transaction_initiated"
-        );
-
-        let pending_tx = self.source_provider.send_transaction(tx_request).await?;
-        let tx_hash = *pending_tx.tx_hash();
-
-        info!(
-            tx_hash = %tx_hash,
-            version = "v2",
-            event = "burn_transaction_sent"
-        );
-
-        Ok(tx_hash)
+        Ok(tx_request)
     }
 
     /// Complete a transfer by minting USDC on the destination chain
@@ -643,18 +1103,48 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         attestation: AttestationBytes,
         from: Address,
     ) -> Result<TxHash> {
-        let message_transmitter_address = self.message_transmitter_v2_contract()?;
+        self.mint_with_options(
+            message_bytes,
+            attestation,
+            from,
+            &self.default_destination_tx_options(),
+        )
+        .await
+    }
 
-        let message_transmitter = MessageTransmitterV2Contract::new(
-            message_transmitter_address,
-            self.destination_provider.clone(),
-        );
+    /// Mint from the configured [`Self::destination_signer`]
+    ///
+    /// Same as [`Self::mint`] with `from` set to the destination signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if no destination signer is
+    /// configured.
+    pub async fn mint_from_signer(
+        &self,
+        message_bytes: Vec<u8>,
+        attestation: AttestationBytes,
+    ) -> Result<TxHash> {
+        let from = self.required_destination_signer()?;
+        self.mint(message_bytes, attestation, from).await
+    }
 
-        let tx_request = message_transmitter.receive_message_transaction(
-            Bytes::from(message_bytes.clone()),
-            Bytes::from(attestation.clone()),
-            from,
-        );
+    /// Mint with explicit gas, nonce, fee or access-list overrides
+    ///
+    /// Same as [`Self::mint`], but applies `options` instead of the bridge's
+    /// default [`destination_tx_options`](Self::destination_tx_options).
+    pub async fn mint_with_options(
+        &self,
+        message_bytes: Vec<u8>,
+        attestation: AttestationBytes,
+        from: Address,
+        options: &TxOptions<DN>,
+    ) -> Result<TxHash> {
+        self.check_destination_sender(from)?;
+        let mut tx_request = self.mint_transaction_request(&message_bytes, &attestation, from)?;
+        options
+            .apply(&self.destination_provider, &mut tx_request)
+            .await?;
 
         info!(
             from = %from,
@@ -664,10 +1154,7 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             event = "mint_transaction_initiated"
         );
 
-        let pending_tx = self
-            .destination_provider
-            .send_transaction(tx_request)
-            .await?;
+        let pending_tx = self.send_destination(from, tx_request).await?;
         let tx_hash = *pending_tx.tx_hash();
 
         info!(
@@ -679,11 +1166,152 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         Ok(tx_hash)
     }
 
+    /// Dry-run the mint with `eth_call` without sending it
+    ///
+    /// Simulates the `receiveMessage` call that [`Self::mint`] would send,
+    /// against the latest destination-chain block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::SimulationReverted`] with a [`ContractRevert`](crate::ContractRevert)
+    /// such as `InvalidAttestation`, `NonceAlreadyUsed`, `MessageExpired`,
+    /// `InvalidDestinationCaller` or `Paused` if the mint would revert.
+    pub async fn simulate_mint(
+        &self,
+        message_bytes: &[u8],
+        attestation: &[u8],
+        from: Address,
+    ) -> Result<()> {
+        let tx_request = self.mint_transaction_request(message_bytes, attestation, from)?;
+        simulate_call(&self.destination_provider, tx_request, "mint").await
+    }
+
+    /// Builds the `receiveMessage` call for a message and its attestation
+    pub(super) fn mint_transaction_request(
+        &self,
+        message_bytes: &[u8],
+        attestation: &[u8],
+        from: Address,
+    ) -> Result<DN::TransactionRequest> {
+        let message_transmitter = MessageTransmitterV2Contract::new(
+            self.message_transmitter_v2_contract()?,
+            self.destination_provider.clone(),
+        );
+
+        Ok(message_transmitter.receive_message_transaction(
+            Bytes::copy_from_slice(message_bytes),
+            Bytes::copy_from_slice(attestation),
+            from,
+        ))
+    }
+
+    /// Wait for a burn transaction to be confirmed and check that it succeeded
+    ///
+    /// Waits until the transaction is buried under the configured number of
+    /// [`confirmations`](Self::confirmations) (one if unset). Polling Iris
+    /// for a burn that reverted would otherwise spin until the attestation
+    /// timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::BurnReverted`] with the decoded revert reason if
+    /// the transaction was mined but failed.
+    pub async fn wait_for_burn_receipt(&self, burn_tx: TxHash) -> Result<ReceiptSummary> {
+        confirm_transaction(
+            &self.source_provider,
+            burn_tx,
+            self.required_confirmations(),
+            TxKind::Burn,
+        )
+        .await
+    }
+
+    /// Wait for a mint transaction to be confirmed and check that it succeeded
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::MintReverted`] if the transaction was mined but
+    /// failed. A mint that lost the race to another relayer reverts with a
+    /// used-nonce reason, which [`CctpError::is_already_relayed`] recognises.
+    pub async fn wait_for_mint_receipt(&self, mint_tx: TxHash) -> Result<ReceiptSummary> {
+        confirm_transaction(
+            &self.destination_provider,
+            mint_tx,
+            self.required_confirmations(),
+            TxKind::Mint,
+        )
+        .await
+    }
+
+    /// Like [`Self::wait_for_mint_receipt`], but returns the network's full receipt
+    ///
+    /// With an OP-stack or `AnyNetwork` destination provider this exposes
+    /// L2-specific fields, such as the L1 data fee, that [`ReceiptSummary`]
+    /// leaves out.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // bridge: CctpV2<SP, DP, Ethereum, AnyNetwork>, minting on an OP Stack chain
+    /// let receipt = bridge.mint_receipt(mint_tx).await?;
+    /// let l1_fee = receipt.other.get_deserialized::<U256>("l1Fee");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::wait_for_mint_receipt`].
+    pub async fn mint_receipt(&self, mint_tx: TxHash) -> Result<DN::ReceiptResponse> {
+        confirm_receipt(
+            &self.destination_provider,
+            mint_tx,
+            self.required_confirmations(),
+            TxKind::Mint,
+        )
+        .await
+    }
+
+    /// Burn USDC and wait for the burn receipt
+    ///
+    /// Equivalent to [`Self::burn`] followed by [`Self::wait_for_burn_receipt`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match bridge.burn_and_confirm(amount, from, usdc).await {
+    ///     Ok(receipt) => println!("Burned in block {:?}", receipt.block_number),
+    ///     Err(CctpError::BurnReverted(revert)) => eprintln!("Burn reverted: {revert}"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// ```
+    pub async fn burn_and_confirm(
+        &self,
+        amount: U256,
+        from: Address,
+        token_address: Address,
+    ) -> Result<ReceiptSummary> {
+        let burn_tx = self.burn(amount, from, token_address).await?;
+        self.wait_for_burn_receipt(burn_tx).await
+    }
+
+    /// Mint USDC and wait for the mint receipt
+    ///
+    /// Equivalent to [`Self::mint`] followed by [`Self::wait_for_mint_receipt`].
+    pub async fn mint_and_confirm(
+        &self,
+        message_bytes: Vec<u8>,
+        attestation: AttestationBytes,
+        from: Address,
+    ) -> Result<ReceiptSummary> {
+        let mint_tx = self.mint(message_bytes, attestation, from).await?;
+        self.wait_for_mint_receipt(mint_tx).await
+    }
+
     /// Check if a message has already been received on the destination chain
     ///
-    /// This queries the on-chain `usedNonces` mapping to determine if the message
-    /// was already processed (by us or a third-party relayer). Use this to check
-    /// transfer status without attempting to mint.
+    /// This queries the on-chain `usedNonces` mapping with the nonce from the
+    /// message header to determine if the message was already processed (by us
+    /// or a third-party relayer). Use this to check transfer status without
+    /// attempting to mint.
     ///
     /// # Arguments
     ///
@@ -694,6 +1322,12 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
     /// * `true` if the message has been processed (funds already minted)
     /// * `false` if the message is still pending
     ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::PlaceholderNonce`] for a message taken from the
+    /// `MessageSent` event log, whose nonce is still zero, and
+    /// [`CctpError::InvalidMessage`] if the header cannot be parsed.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -706,22 +1340,25 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
     /// }
     /// ```
     pub async fn is_message_received(&self, message: &[u8]) -> Result<bool> {
+        let header = MessageHeader::parse(message)?;
+        if header.has_placeholder_nonce() {
+            return Err(CctpError::PlaceholderNonce);
+        }
+
         let message_transmitter_address = self.message_transmitter_v2_contract()?;
         let message_transmitter = MessageTransmitterV2Contract::new(
             message_transmitter_address,
             self.destination_provider.clone(),
         );
 
-        let message_hash: [u8; 32] = alloy_primitives::keccak256(message).into();
-
         debug!(
-            message_hash = %hex::encode(message_hash),
+            nonce = %header.nonce,
             version = "v2",
             event = "checking_message_received_status"
         );
 
         Ok(message_transmitter
-            .is_message_received(message_hash)
+            .is_message_received(header.nonce)
             .await?)
     }
 
@@ -765,11 +1402,11 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         let max_attempts = max_attempts.unwrap_or(60);
         let poll_interval = poll_interval.unwrap_or_else(|| {
             if self.fast_transfer {
-                self.destination_chain
+                self.destination_config()
                     .fast_transfer_confirmation_time_seconds()
                     .unwrap_or(5)
             } else {
-                self.destination_chain
+                self.destination_config()
                     .standard_transfer_confirmation_time_seconds()
                     .unwrap_or(60)
             }
@@ -921,6 +1558,151 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         Ok(erc20.allowance(owner, spender).await?)
     }
 
+    /// Returns the native USDC address on the source chain
+    pub fn usdc_address(&self) -> Result<Address> {
+        self.source_config().usdc_address()
+    }
+
+    /// Returns the native USDC address on the destination chain
+    pub fn destination_usdc_address(&self) -> Result<Address> {
+        self.destination_config().usdc_address()
+    }
+
+    /// [`Self::get_allowance`] for the source chain's native USDC
+    pub async fn get_usdc_allowance(&self, owner: Address) -> Result<U256> {
+        self.get_allowance(self.usdc_address()?, owner).await
+    }
+
+    /// [`Self::ensure_approval`] for the source chain's native USDC
+    pub async fn ensure_usdc_approval(
+        &self,
+        owner: Address,
+        amount: U256,
+    ) -> Result<Option<TxHash>> {
+        self.ensure_approval(self.usdc_address()?, owner, amount)
+            .await
+    }
+
+    /// [`Self::burn`] the source chain's native USDC
+    pub async fn burn_usdc(&self, amount: U256, from: Address) -> Result<TxHash> {
+        self.burn(amount, from, self.usdc_address()?).await
+    }
+
+    /// [`Self::transfer`] the source chain's native USDC
+    pub async fn transfer_usdc(&self, amount: U256, from: Address) -> Result<(TxHash, TxHash)> {
+        self.transfer(amount, from, self.usdc_address()?).await
+    }
+
+    /// Check the USDC address table against the destination `TokenMinterV2`
+    ///
+    /// Reads `getLocalToken(sourceDomain, sourceUsdc)` on the destination
+    /// chain, which is the token a mint of this route pays out, and compares
+    /// it with [`Self::destination_usdc_address`]. A source USDC address the
+    /// minter does not know about links to `Address::ZERO`, so one call
+    /// checks both sides of the route.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::UsdcAddressMismatch`] if the addresses differ.
+    pub async fn verify_usdc_address(&self) -> Result<()> {
+        let source_domain = self.source_config().cctp_v2_domain_id()?;
+        let source_usdc = self.usdc_address()?;
+        let expected = self.destination_usdc_address()?;
+
+        let destination_token_messenger = match self.custom_contracts() {
+            Some((token_messenger, _)) => token_messenger,
+            None => self.destination_config().token_messenger_v2_address()?,
+        };
+        let token_messenger = TokenMessengerV2Contract::new(
+            destination_token_messenger,
+            self.destination_provider.clone(),
+        );
+        let token_minter = TokenMinterV2Contract::new(
+            token_messenger.local_minter().await?,
+            self.destination_provider.clone(),
+        );
+        let actual = token_minter
+            .get_local_token(source_domain, source_usdc.into_word())
+            .await?;
+
+        if actual != expected {
+            error!(
+                source_domain = %source_domain,
+                source_usdc = %source_usdc,
+                expected = %expected,
+                actual = %actual,
+                version = "v2",
+                event = "usdc_address_mismatch"
+            );
+            return Err(CctpError::UsdcAddressMismatch { expected, actual });
+        }
+
+        debug!(
+            source_usdc = %source_usdc,
+            destination_usdc = %expected,
+            version = "v2",
+            event = "usdc_address_verified"
+        );
+        Ok(())
+    }
+
+    /// Discover the source chain's `TokenMinterV2` address
+    ///
+    /// Reads `localMinter` from the source `TokenMessengerV2` rather than
+    /// relying on a hard-coded address.
+    pub async fn local_minter(&self) -> Result<Address> {
+        let token_messenger = TokenMessengerV2Contract::new(
+            self.token_messenger_v2_contract()?,
+            self.source_provider.clone(),
+        );
+
+        Ok(token_messenger.local_minter().await?)
+    }
+
+    /// Maximum amount of `token_address` that a single burn may move
+    ///
+    /// Queries `burnLimitsPerMessage` on the source chain's `TokenMinterV2`.
+    /// A zero limit means the token cannot be burned at all.
+    pub async fn burn_limit_per_message(&self, token_address: Address) -> Result<U256> {
+        let token_minter =
+            TokenMinterV2Contract::new(self.local_minter().await?, self.source_provider.clone());
+
+        Ok(token_minter.burn_limit_per_message(token_address).await?)
+    }
+
+    /// Check an amount against the per-message burn limit before burning
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::BurnLimitExceeded`] with the on-chain limit if
+    /// `amount` is above it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if let Err(CctpError::BurnLimitExceeded { limit, .. }) =
+    ///     bridge.check_burn_limit(amount, usdc).await
+    /// {
+    ///     println!("Split the transfer into chunks of at most {limit}");
+    /// }
+    /// ```
+    pub async fn check_burn_limit(&self, amount: U256, token_address: Address) -> Result<()> {
+        let limit = self.burn_limit_per_message(token_address).await?;
+
+        if amount > limit {
+            error!(
+                amount = %amount,
+                limit = %limit,
+                token_address = %token_address,
+                version = "v2",
+                event = "burn_limit_exceeded"
+            );
+            return Err(CctpError::BurnLimitExceeded { amount, limit });
+        }
+
+        Ok(())
+    }
+
     /// Approve the `TokenMessenger` contract to spend tokens
     ///
     /// This must be called before `burn` if the `TokenMessenger` doesn't have
@@ -966,10 +1748,29 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         owner: Address,
         amount: U256,
     ) -> Result<TxHash> {
+        self.approve_with_options(token_address, owner, amount, &self.default_tx_options())
+            .await
+    }
+
+    /// Approve with explicit gas, nonce, fee or access-list overrides
+    ///
+    /// Same as [`Self::approve`], but applies `options` instead of the
+    /// bridge's default [`tx_options`](Self::tx_options).
+    pub async fn approve_with_options(
+        &self,
+        token_address: Address,
+        owner: Address,
+        amount: U256,
+        options: &TxOptions<SN>,
+    ) -> Result<TxHash> {
+        self.check_source_sender(owner)?;
         let spender = self.token_messenger_v2_contract()?;
         let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
 
-        let tx_request = erc20.approve_transaction(owner, spender, amount);
+        let mut tx_request = erc20.approve_transaction(owner, spender, amount);
+        options
+            .apply(&self.source_provider, &mut tx_request)
+            .await?;
 
         info!(
             owner = %owner,
@@ -980,7 +1781,7 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             event = "approval_transaction_initiated"
         );
 
-        let pending_tx = self.source_provider.send_transaction(tx_request).await?;
+        let pending_tx = self.send_source(owner, tx_request).await?;
         let tx_hash = *pending_tx.tx_hash();
 
         info!(
@@ -992,11 +1793,208 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         Ok(tx_hash)
     }
 
+    /// Read and verify the source token's EIP-712 domain
+    ///
+    /// Builds the domain from the token's `name()`, `version()` and the
+    /// source chain id, and checks it against the token's
+    /// `DOMAIN_SEPARATOR()` so a signature is never requested over the wrong
+    /// domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if the token's version is not
+    /// USDC's, or if the computed separator differs from the on-chain one.
+    pub async fn usdc_domain(&self, token_address: Address) -> Result<Eip712Domain> {
+        let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
+        let name = erc20.name().await?;
+        let version = erc20.version().await?;
+        if version != USDC_EIP712_VERSION {
+            return Err(CctpError::InvalidConfig(format!(
+                "token {token_address} has EIP-712 version {version:?}, expected {USDC_EIP712_VERSION:?}"
+            )));
+        }
+
+        let chain_id = self.source_provider.get_chain_id().await?;
+        let domain = usdc_eip712_domain(name, chain_id, token_address);
+        let on_chain = erc20.domain_separator().await?;
+        if domain.separator() != on_chain {
+            return Err(CctpError::InvalidConfig(format!(
+                "EIP-712 domain of token {token_address} does not match its DOMAIN_SEPARATOR {on_chain}"
+            )));
+        }
+
+        Ok(domain)
+    }
+
+    /// Next EIP-2612 permit nonce of `owner` on the source token
+    pub async fn permit_nonce(&self, token_address: Address, owner: Address) -> Result<U256> {
+        let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
+        Ok(erc20.nonces(owner).await?)
+    }
+
+    /// Whether an EIP-3009 authorization nonce was already used on the source token
+    pub async fn authorization_used(
+        &self,
+        token_address: Address,
+        authorizer: Address,
+        nonce: B256,
+    ) -> Result<bool> {
+        let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
+        Ok(erc20.authorization_state(authorizer, nonce).await?)
+    }
+
+    /// Build the EIP-2612 permit that lets the `TokenMessengerV2` burn `amount`
+    ///
+    /// Fills in the verified domain and the owner's current nonce. Sign
+    /// [`PermitRequest::signing_hash`] with the owner's key and pass the result
+    /// to [`Self::burn_with_permit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let request = bridge.build_burn_permit(usdc, owner, amount, deadline).await?;
+    /// let signature = signer.sign_hash(&request.signing_hash()).await?;
+    /// let (permit_tx, burn_tx) = bridge.burn_with_permit(&request, &signature).await?;
+    /// ```
+    pub async fn build_burn_permit(
+        &self,
+        token_address: Address,
+        owner: Address,
+        amount: U256,
+        deadline: U256,
+    ) -> Result<PermitRequest> {
+        let domain = self.usdc_domain(token_address).await?;
+        let nonce = self.permit_nonce(token_address, owner).await?;
+
+        Ok(PermitRequest {
+            domain,
+            permit: Permit {
+                owner,
+                spender: self.token_messenger_v2_contract()?,
+                value: amount,
+                nonce,
+                deadline,
+            },
+        })
+    }
+
+    /// Burn using a signed EIP-2612 permit instead of an `approve` transaction
+    ///
+    /// Sends the permit and then the burn from the permit's owner, with
+    /// consecutive nonces and without waiting for the permit's receipt, so
+    /// both can land in the same block. `depositForBurn` burns from its
+    /// caller, so the owner sends both; anyone may submit a permit, but a
+    /// relayer could not burn with it.
+    ///
+    /// The burn cannot be gas-estimated before the permit is mined, so it is
+    /// sent with the gas limit of the bridge's
+    /// [`tx_options`](Self::tx_options), or [`PERMIT_BURN_GAS_LIMIT`] when
+    /// none is set. Raise it for burns with large hook data.
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (`permit_tx_hash`, `burn_tx_hash`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if the permit's spender is not the
+    /// source `TokenMessengerV2`, its domain names no token, or its owner is
+    /// not the configured source signer.
+    pub async fn burn_with_permit(
+        &self,
+        request: &PermitRequest,
+        signature: &Signature,
+    ) -> Result<(TxHash, TxHash)> {
+        let permit = &request.permit;
+        let owner = permit.owner;
+        let token_messenger = self.token_messenger_v2_contract()?;
+        if permit.spender != token_messenger {
+            return Err(CctpError::InvalidConfig(format!(
+                "permit spender {} is not the TokenMessengerV2 {token_messenger}",
+                permit.spender
+            )));
+        }
+        let token_address = request.domain.verifying_contract.ok_or_else(|| {
+            CctpError::InvalidConfig("permit domain has no verifying contract".to_string())
+        })?;
+        self.check_source_sender(owner)?;
+
+        let options = self.default_tx_options();
+        let erc20 = Erc20Contract::new(token_address, self.source_provider.clone());
+        let mut permit_request = erc20.permit_transaction(permit, signature, owner);
+        options
+            .apply(&self.source_provider, &mut permit_request)
+            .await?;
+        let mut burn_request = self.burn_transaction_request(permit.value, owner, token_address)?;
+        options
+            .with_gas_limit_or(PERMIT_BURN_GAS_LIMIT)
+            .apply(&self.source_provider, &mut burn_request)
+            .await?;
+
+        // The nonce manager hands out consecutive nonces itself
+        let first_nonce = match self.nonce_manager {
+            Some(_) => None,
+            None => Some(
+                self.source_provider
+                    .get_transaction_count(owner)
+                    .pending()
+                    .await?,
+            ),
+        };
+        if let Some(nonce) = first_nonce {
+            permit_request.set_nonce(nonce);
+            burn_request.set_nonce(nonce + 1);
+        }
+
+        let permit_tx = *self.send_source(owner, permit_request).await?.tx_hash();
+        info!(
+            tx_hash = %permit_tx,
+            owner = %owner,
+            nonce = ?first_nonce,
+            version = "v2",
+            event = "permit_transaction_sent"
+        );
+
+        let burn_tx = *self.send_source(owner, burn_request).await?.tx_hash();
+        info!(
+            tx_hash = %burn_tx,
+            amount = %permit.value,
+            token_address = %token_address,
+            version = "v2",
+            event = "burn_transaction_sent"
+        );
+
+        Ok((permit_tx, burn_tx))
+    }
+
+    /// Approve the `TokenMessenger` contract and wait for the approval receipt
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::ApprovalReverted`] if the approval was mined but
+    /// failed.
+    pub async fn approve_and_confirm(
+        &self,
+        token_address: Address,
+        owner: Address,
+        amount: U256,
+    ) -> Result<ReceiptSummary> {
+        let approval_tx = self.approve(token_address, owner, amount).await?;
+        confirm_transaction(
+            &self.source_provider,
+            approval_tx,
+            self.required_confirmations(),
+            TxKind::Approval,
+        )
+        .await
+    }
+
     /// Check if approval is needed and approve if necessary
     ///
     /// This is a convenience method that combines `get_allowance` and `approve`.
     /// It only sends an approval transaction if the current allowance is less than
-    /// the requested amount.
+    /// the requested amount, and waits for that approval to be confirmed so that
+    /// a following `burn` sees the new allowance.
     ///
     /// # Arguments
     ///
@@ -1006,7 +2004,12 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
     ///
     /// # Returns
     ///
-    /// `Some(tx_hash)` if an approval was sent, `None` if approval was already sufficient
+    /// `Some(tx_hash)` if an approval was sent and confirmed, `None` if approval was
+    /// already sufficient
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::ApprovalReverted`] if the approval was mined but failed.
     ///
     /// # Example
     ///
@@ -1057,8 +2060,10 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             event = "approval_needed"
         );
 
-        let tx_hash = self.approve(token_address, owner, amount).await?;
-        Ok(Some(tx_hash))
+        let receipt = self
+            .approve_and_confirm(token_address, owner, amount)
+            .await?;
+        Ok(Some(receipt.tx_hash))
     }
 
     /// Execute a full cross-chain transfer: burn + wait for attestation + mint
@@ -1114,8 +2119,13 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
             event = "full_transfer_initiated"
         );
 
-        // Step 1: Burn tokens on source chain
+        // Step 1: Burn tokens on source chain, confirming the receipt first
+        // when configured so a reverted burn fails fast instead of timing out
         let burn_tx_hash = self.burn(amount, from, token_address).await?;
+        let burn_tx_hash = self.included_burn(burn_tx_hash).await?;
+        if self.confirmations.is_some() {
+            self.wait_for_burn_receipt(burn_tx_hash).await?;
+        }
 
         info!(
             burn_tx_hash = %burn_tx_hash,
@@ -1142,7 +2152,13 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         );
 
         // Step 3: Mint tokens on destination chain
-        let mint_tx_hash = self.mint(message_bytes, attestation, from).await?;
+        let mint_tx_hash = self
+            .mint(message_bytes, attestation, self.mint_sender(from))
+            .await?;
+        let mint_tx_hash = self.included_mint(mint_tx_hash).await?;
+        if self.confirmations.is_some() {
+            self.wait_for_mint_receipt(mint_tx_hash).await?;
+        }
 
         info!(
             burn_tx_hash = %burn_tx_hash,
@@ -1154,6 +2170,24 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
         Ok((burn_tx_hash, mint_tx_hash))
     }
 
+    /// Execute a full transfer from the configured [`Self::source_signer`]
+    ///
+    /// Same as [`Self::transfer`] with `from` set to the source signer. The
+    /// mint is sent by the destination signer if one is configured, and by
+    /// the source signer otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if no source signer is configured.
+    pub async fn transfer_from_signer(
+        &self,
+        amount: U256,
+        token_address: Address,
+    ) -> Result<(TxHash, TxHash)> {
+        self.transfer(amount, self.required_source_signer()?, token_address)
+            .await
+    }
+
     /// Constructs the Iris API v2 URL for attestation polling
     ///
     /// The v2 API uses a different endpoint format than v1:
@@ -1189,38 +2223,196 @@ impl<P: Provider<Ethereum> + Clone> CctpV2<P> {
     /// # }
     /// ```
     ///
-    /// See <https://developers.circle.com/cctp/transfer-usdc-on-testnet-from-ethereum-to-avalanche>
-    pub fn create_url(&self, tx_hash: TxHash) -> Result<Url> {
-        let source_domain = self.source_chain.cctp_v2_domain_id()?.as_u32();
-        Ok(self.api_url().join(&format!(
-            "{MESSAGES_PATH_V2}{source_domain}?transactionHash={tx_hash}"
-        ))?)
+    /// See <https://developers.circle.com/cctp/transfer-usdc-on-testnet-from-ethereum-to-avalanche>
+    pub fn create_url(&self, tx_hash: TxHash) -> Result<Url> {
+        let source_domain = self.source_config().cctp_v2_domain_id()?.as_u32();
+        Ok(self.api_url().join(&format!(
+            "{MESSAGES_PATH_V2}{source_domain}?transactionHash={tx_hash}"
+        ))?)
+    }
+
+    /// Fetches the attestation response from the CCTP v2 API
+    ///
+    /// # Arguments
+    ///
+    /// * `client`: The HTTP client to use
+    /// * `url`: The URL to get the attestation from
+    ///
+    async fn fetch_attestation_response(&self, client: &Client, url: &Url) -> Result<Response> {
+        client
+            .get(url.as_str())
+            .send()
+            .await
+            .map_err(CctpError::Network)
+    }
+}
+
+/// Rejects a provider whose `eth_chainId` is not `chain`'s id
+async fn check_provider_chain<P: Provider<N>, N: Network>(
+    side: &str,
+    provider: &P,
+    chain: Chain,
+) -> Result<()> {
+    let actual = provider.get_chain_id().await?;
+    let expected = chain.id();
+    if actual != expected {
+        error!(
+            side = side,
+            expected_chain_id = expected,
+            actual_chain_id = actual,
+            version = "v2",
+            event = "provider_chain_mismatch"
+        );
+        return Err(CctpError::InvalidConfig(format!(
+            "{side} provider is on chain {actual}, not {chain} ({expected})"
+        )));
+    }
+    Ok(())
+}
+
+impl<SP, DP, SN, DN, S> CctpV2Builder<SP, DP, SN, DN, S>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+    S: cctp_v2_builder::IsComplete,
+{
+    /// Build the bridge and [`validate`](CctpV2::validate) it
+    ///
+    /// Unlike `build`, this fails up front on an unsupported chain, a
+    /// source equal to the destination, chains in different environments,
+    /// a mainnet or testnet `environment` other than the chains' own, or a
+    /// provider connected to a different chain than declared.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let bridge = CctpV2Bridge::builder()
+    ///     .source_chain(NamedChain::Mainnet)
+    ///     .destination_chain(NamedChain::Base)
+    ///     .source_provider(ethereum)
+    ///     .destination_provider(base)
+    ///     .recipient(recipient)
+    ///     .try_build()
+    ///     .await?;
+    /// ```
+    pub async fn try_build(self) -> Result<CctpV2<SP, DP, SN, DN>> {
+        let bridge = self.build();
+        bridge.validate().await?;
+        Ok(bridge)
+    }
+}
+
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + WalletProvider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Take the source signer from the source provider's wallet
+    ///
+    /// Sets [`Self::source_signer`] to the wallet's default signer when none
+    /// is configured, and otherwise checks that the wallet can sign for the
+    /// configured one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let bridge = CctpV2Bridge::builder()
+    ///     .source_chain(NamedChain::Mainnet)
+    ///     .destination_chain(NamedChain::Base)
+    ///     .source_provider(ProviderBuilder::new().wallet(treasury_key).connect_http(eth_rpc))
+    ///     .destination_provider(ProviderBuilder::new().wallet(relayer_key).connect_http(base_rpc))
+    ///     .recipient(recipient)
+    ///     .build()
+    ///     .with_source_wallet()?
+    ///     .with_destination_wallet()?;
+    /// let (burn_tx, mint_tx) = bridge.transfer_from_signer(amount, usdc).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::InvalidConfig`] if the wallet has no key for the
+    /// configured source signer.
+    pub fn with_source_wallet(mut self) -> Result<Self> {
+        self.source_signer = Some(wallet_signer(
+            "source",
+            &self.source_provider,
+            self.source_signer,
+        )?);
+        Ok(self)
     }
+}
 
-    /// Fetches the attestation response from the CCTP v2 API
+impl<SP, DP, SN, DN> CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + WalletProvider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    /// Take the destination signer from the destination provider's wallet
     ///
-    /// # Arguments
+    /// The destination counterpart of [`Self::with_source_wallet`].
     ///
-    /// * `client`: The HTTP client to use
-    /// * `url`: The URL to get the attestation from
+    /// # Errors
     ///
-    async fn fetch_attestation_response(&self, client: &Client, url: &Url) -> Result<Response> {
-        client
-            .get(url.as_str())
-            .send()
-            .await
-            .map_err(CctpError::Network)
+    /// Returns [`CctpError::InvalidConfig`] if the wallet has no key for the
+    /// configured destination signer.
+    pub fn with_destination_wallet(mut self) -> Result<Self> {
+        self.destination_signer = Some(wallet_signer(
+            "destination",
+            &self.destination_provider,
+            self.destination_signer,
+        )?);
+        Ok(self)
+    }
+}
+
+/// The configured signer, checked against `provider`'s wallet, or else the
+/// wallet's default signer
+pub(super) fn wallet_signer<P: WalletProvider<N>, N: Network>(
+    side: &str,
+    provider: &P,
+    configured: Option<Address>,
+) -> Result<Address> {
+    match configured {
+        Some(signer) if !provider.has_signer_for(&signer) => {
+            Err(CctpError::InvalidConfig(format!(
+                "the {side} provider's wallet cannot sign for the configured {side} signer {signer}"
+            )))
+        }
+        Some(signer) => Ok(signer),
+        None => Ok(provider.default_signer_address()),
+    }
+}
+
+/// Rejects `from` when a different signer is configured for that side
+pub(super) fn check_sender(side: &str, signer: Option<Address>, from: Address) -> Result<()> {
+    match signer {
+        Some(signer) if signer != from => Err(CctpError::InvalidConfig(format!(
+            "{from} is not the configured {side} signer {signer}"
+        ))),
+        _ => Ok(()),
     }
 }
 
 // Implement CctpBridge trait for v2 CctpV2 struct
 #[async_trait]
-impl<P: Provider<Ethereum> + Clone> CctpBridge for CctpV2<P> {
-    fn source_chain(&self) -> NamedChain {
+impl<SP, DP, SN, DN> CctpBridge for CctpV2<SP, DP, SN, DN>
+where
+    SP: Provider<SN> + Clone,
+    DP: Provider<DN> + Clone,
+    SN: Network,
+    DN: Network,
+{
+    fn source_chain(&self) -> Chain {
         self.source_chain
     }
 
-    fn destination_chain(&self) -> NamedChain {
+    fn destination_chain(&self) -> Chain {
         self.destination_chain
     }
 
@@ -1248,6 +2440,8 @@ impl<P: Provider<Ethereum> + Clone> CctpBridge for CctpV2<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bridge::IRIS_API_SANDBOX;
+    use crate::test_utils::mocked;
     use alloy_chains::NamedChain;
     use alloy_primitives::{Address, FixedBytes};
     use alloy_provider::ProviderBuilder;
@@ -1440,14 +2634,229 @@ mod tests {
             .hook_data(Bytes::from(vec![1, 2, 3]))
             .build();
 
-        assert_eq!(bridge.source_chain(), &NamedChain::Mainnet);
-        assert_eq!(bridge.destination_chain(), &NamedChain::Linea);
+        assert_eq!(bridge.source_chain().named(), Some(NamedChain::Mainnet));
+        assert_eq!(bridge.destination_chain().named(), Some(NamedChain::Linea));
         assert_eq!(bridge.recipient(), &Address::ZERO);
         assert!(bridge.is_fast_transfer());
         assert_eq!(bridge.max_fee(), Some(U256::from(500)));
         assert!(bridge.hook_data().is_some());
     }
 
+    #[test]
+    fn test_v2_confirmations_default_to_one_block() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider.clone())
+            .recipient(Address::ZERO)
+            .build();
+        assert_eq!(bridge.confirmations(), None);
+        assert_eq!(bridge.required_confirmations(), 1);
+
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .confirmations(3)
+            .build();
+        assert_eq!(bridge.confirmations(), Some(3));
+        assert_eq!(bridge.required_confirmations(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_v2_per_side_providers_and_signers() {
+        let treasury = Address::repeat_byte(0x01);
+        let relayer = Address::repeat_byte(0x02);
+
+        // Differently layered provider stacks on each side
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(
+                ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap()),
+            )
+            .destination_provider(
+                ProviderBuilder::new()
+                    .disable_recommended_fillers()
+                    .connect_http("http://localhost:8546".parse().unwrap()),
+            )
+            .recipient(Address::ZERO)
+            .source_signer(treasury)
+            .destination_signer(relayer)
+            .build();
+
+        assert_eq!(bridge.source_signer(), Some(treasury));
+        assert_eq!(bridge.destination_signer(), Some(relayer));
+        assert_eq!(bridge.mint_sender(treasury), relayer);
+
+        // Mismatched senders are rejected before anything is sent
+        let err = bridge
+            .burn(U256::from(1), relayer, Address::ZERO)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+        let err = bridge.mint(vec![], vec![], treasury).await.unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+    }
+
+    #[tokio::test]
+    async fn test_v2_signers_from_provider_wallets() {
+        use alloy_signer_local::PrivateKeySigner;
+
+        let treasury = PrivateKeySigner::random();
+        let relayer = PrivateKeySigner::random();
+        let builder = || {
+            CctpV2::builder()
+                .source_chain(NamedChain::Mainnet)
+                .destination_chain(NamedChain::Base)
+                .source_provider(
+                    ProviderBuilder::new()
+                        .wallet(treasury.clone())
+                        .connect_http("http://localhost:8545".parse().unwrap()),
+                )
+                .destination_provider(
+                    ProviderBuilder::new()
+                        .wallet(relayer.clone())
+                        .connect_http("http://localhost:8546".parse().unwrap()),
+                )
+                .recipient(Address::ZERO)
+        };
+
+        // Unset signers are taken from the wallets
+        let bridge = builder()
+            .build()
+            .with_source_wallet()
+            .unwrap()
+            .with_destination_wallet()
+            .unwrap();
+        assert_eq!(bridge.source_signer(), Some(treasury.address()));
+        assert_eq!(bridge.destination_signer(), Some(relayer.address()));
+        assert_eq!(bridge.mint_sender(treasury.address()), relayer.address());
+
+        // A configured signer must be one the wallet holds
+        let err = builder()
+            .source_signer(relayer.address())
+            .build()
+            .with_source_wallet()
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("source provider")));
+        builder()
+            .destination_signer(relayer.address())
+            .build()
+            .with_destination_wallet()
+            .unwrap();
+
+        // The `*_from_signer` methods need a signer before sending anything
+        let unsigned = builder().build();
+        let err = unsigned
+            .burn_from_signer(U256::from(1), Address::ZERO)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("source signer")));
+        let err = unsigned.mint_from_signer(vec![], vec![]).await.unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("destination signer")));
+        let err = unsigned
+            .transfer_from_signer(U256::from(1), Address::ZERO)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn test_v2_mixed_networks() {
+        use alloy_network::{AnyNetwork, AnyTransactionReceipt, TransactionBuilder};
+        use std::future::Future;
+
+        // `mint_receipt` returns the destination network's receipt
+        fn mint_receipt<SP, DP>(
+            bridge: &CctpV2<SP, DP, Ethereum, AnyNetwork>,
+        ) -> impl Future<Output = Result<AnyTransactionReceipt>> + '_
+        where
+            SP: Provider<Ethereum> + Clone,
+            DP: Provider<AnyNetwork> + Clone,
+        {
+            bridge.mint_receipt(TxHash::ZERO)
+        }
+
+        let ethereum =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let base = ProviderBuilder::new()
+            .network::<AnyNetwork>()
+            .connect_http("http://localhost:8546".parse().unwrap());
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(ethereum)
+            .destination_provider(base)
+            .recipient(Address::repeat_byte(0x02))
+            .tx_options(TxOptions::<Ethereum>::builder().gas_limit(200_000).build())
+            .destination_tx_options(
+                TxOptions::<AnyNetwork>::builder()
+                    .gas_limit(300_000)
+                    .build(),
+            )
+            .build();
+        drop(mint_receipt(&bridge));
+
+        let from = Address::repeat_byte(0x01);
+        let burn: <Ethereum as Network>::TransactionRequest = bridge
+            .burn_transaction_request(U256::from(1_000_000), from, Address::repeat_byte(0x0b))
+            .unwrap();
+        assert_eq!(
+            TransactionBuilder::to(&burn),
+            Some(bridge.token_messenger_v2_contract().unwrap())
+        );
+        let mint: <AnyNetwork as Network>::TransactionRequest = bridge
+            .mint_transaction_request(&[0x01], &[0x02], from)
+            .unwrap();
+        assert_eq!(TransactionBuilder::from(&mint), Some(from));
+        assert_eq!(bridge.tx_options().unwrap().gas_limit(), Some(200_000));
+        assert_eq!(
+            bridge.destination_tx_options().unwrap().gas_limit(),
+            Some(300_000)
+        );
+    }
+
+    #[test]
+    fn test_v2_any_network_provider() {
+        use alloy_network::{AnyNetwork, TransactionBuilder};
+
+        let provider = ProviderBuilder::new()
+            .network::<AnyNetwork>()
+            .connect_http("http://localhost:8545".parse().unwrap());
+        let bridge: CctpV2<_, _, AnyNetwork> = CctpV2::builder()
+            .source_chain(NamedChain::Optimism)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::repeat_byte(0x02))
+            .build();
+
+        let from = Address::repeat_byte(0x01);
+        let burn = bridge
+            .burn_transaction_request(U256::from(1_000_000), from, Address::repeat_byte(0x0b))
+            .unwrap();
+        assert_eq!(TransactionBuilder::from(&burn), Some(from));
+        assert_eq!(
+            burn.to(),
+            Some(bridge.token_messenger_v2_contract().unwrap())
+        );
+
+        let mint = bridge
+            .mint_transaction_request(&[0x01], &[0x02], from)
+            .unwrap();
+        assert_eq!(
+            mint.to(),
+            Some(bridge.message_transmitter_v2_contract().unwrap())
+        );
+    }
+
     // Integration tests for transfer flow logic
 
     #[test]
@@ -1622,6 +3031,397 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_v2_resolves_chains_through_registry() {
+        use crate::{ChainRecord, V2Deployment};
+
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let mut registry = ChainRegistry::builtin();
+        registry.insert(ChainRecord {
+            chain_id: NamedChain::AnvilHardhat.into(),
+            domain: DomainId::Base,
+            environment: CctpEnvironment::Testnet,
+            usdc: None,
+            v1: None,
+            v2: Some(V2Deployment {
+                token_messenger: Address::repeat_byte(0x01),
+                message_transmitter: Address::repeat_byte(0x02),
+                fast_transfer_seconds: 2,
+                standard_transfer_seconds: 2,
+                fast_transfer: true,
+                fast_transfer_fee_bps: None,
+            }),
+        });
+
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::AnvilHardhat)
+            .destination_chain(NamedChain::Sepolia)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .registry(registry)
+            .build();
+
+        assert_eq!(
+            bridge.token_messenger_v2_contract().unwrap(),
+            Address::repeat_byte(0x01)
+        );
+        assert_eq!(
+            bridge.message_transmitter_v2_contract().unwrap(),
+            NamedChain::Sepolia
+                .message_transmitter_v2_address()
+                .unwrap()
+        );
+        assert_eq!(bridge.api_url(), Url::parse(IRIS_API_SANDBOX).unwrap());
+        assert!(bridge
+            .create_url(TxHash::ZERO)
+            .unwrap()
+            .as_str()
+            .contains("/v2/messages/6?"));
+    }
+
+    #[test]
+    fn test_v2_bridges_chains_known_only_by_id() {
+        use crate::{ChainRecord, V2Deployment};
+
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+
+        // Codex has no `NamedChain` variant but is in the built-in tables
+        let codex = CctpV2::builder()
+            .source_chain(81224)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider.clone())
+            .recipient(Address::ZERO)
+            .fast_transfer(true)
+            .build();
+        codex.validate_chains().unwrap();
+        assert_eq!(codex.source_chain().id(), 81224);
+        assert_eq!(
+            codex.token_messenger_v2_contract().unwrap(),
+            NamedChain::Base.token_messenger_v2_address().unwrap()
+        );
+        assert_eq!(codex.environment(), CctpEnvironment::Mainnet);
+
+        // A devnet only described in the registry, without Fast Transfer
+        let devnet = ChainRecord {
+            chain_id: 900_001,
+            domain: DomainId::Base,
+            environment: CctpEnvironment::Testnet,
+            usdc: None,
+            v1: None,
+            v2: Some(V2Deployment {
+                token_messenger: Address::repeat_byte(0x01),
+                message_transmitter: Address::repeat_byte(0x02),
+                fast_transfer_seconds: 2,
+                standard_transfer_seconds: 2,
+                fast_transfer: false,
+                fast_transfer_fee_bps: None,
+            }),
+        };
+        let registry: ChainRegistry = [devnet].into_iter().collect();
+        let builder = || {
+            CctpV2::builder()
+                .source_chain(900_001)
+                .destination_chain(NamedChain::Sepolia)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::ZERO)
+                .registry(registry.clone())
+        };
+        let bridge = builder().build();
+        bridge.validate_chains().unwrap();
+        assert_eq!(
+            bridge.token_messenger_v2_contract().unwrap(),
+            Address::repeat_byte(0x01)
+        );
+
+        let err = builder().fast_transfer(true).build().validate_chains();
+        assert!(matches!(
+            err,
+            Err(CctpError::InvalidConfig(msg)) if msg.contains("does not offer Fast Transfer")
+        ));
+
+        // Without the registry the id is unknown
+        let unknown = CctpV2::builder()
+            .source_chain(900_001)
+            .destination_chain(NamedChain::Sepolia)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .build();
+        assert!(matches!(
+            unknown.validate_chains(),
+            Err(CctpError::UnsupportedChainId(900_001))
+        ));
+    }
+
+    #[test]
+    fn test_v2_custom_environment_replaces_iris_and_contracts() {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let staging = CctpEnvironment::Custom {
+            iris_api: Url::parse("http://localhost:8080").unwrap(),
+            token_messenger: Address::repeat_byte(0x01),
+            message_transmitter: Address::repeat_byte(0x02),
+        };
+
+        // A local fork of mainnet, attested by a mock Iris
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider.clone())
+            .recipient(Address::ZERO)
+            .environment(staging.clone())
+            .build();
+
+        assert_eq!(bridge.environment(), staging);
+        assert!(bridge
+            .create_url(TxHash::ZERO)
+            .unwrap()
+            .as_str()
+            .starts_with("http://localhost:8080/v2/messages/0?"));
+        assert_eq!(
+            bridge.token_messenger_v2_contract().unwrap(),
+            Address::repeat_byte(0x01)
+        );
+        assert_eq!(
+            bridge.message_transmitter_v2_contract().unwrap(),
+            Address::repeat_byte(0x02)
+        );
+        assert!(bridge.validate_chains().is_ok());
+
+        // Without one, the source chain's environment applies
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Sepolia)
+            .destination_chain(NamedChain::BaseSepolia)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .build();
+        assert_eq!(bridge.environment(), CctpEnvironment::Testnet);
+    }
+
+    #[rstest]
+    #[case(NamedChain::Mainnet, NamedChain::Base, CctpEnvironment::Testnet)]
+    #[case(NamedChain::Sepolia, NamedChain::BaseSepolia, CctpEnvironment::Mainnet)]
+    fn test_v2_environment_must_match_chains(
+        #[case] source: NamedChain,
+        #[case] destination: NamedChain,
+        #[case] environment: CctpEnvironment,
+    ) {
+        let provider =
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+        let bridge = |environment: CctpEnvironment| {
+            CctpV2::builder()
+                .source_chain(source)
+                .destination_chain(destination)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::ZERO)
+                .environment(environment)
+                .build()
+        };
+
+        assert!(matches!(
+            bridge(environment).validate_chains(),
+            Err(CctpError::InvalidConfig(_))
+        ));
+
+        // Restating the chains' own environment is allowed
+        let own = bridge(CctpEnvironment::Mainnet)
+            .source_config()
+            .environment();
+        assert!(bridge(own).validate_chains().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_v2_verify_usdc_address() {
+        let (asserter, provider) = mocked();
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .build();
+        assert_eq!(
+            bridge.usdc_address().unwrap(),
+            NamedChain::Mainnet.usdc_address().unwrap()
+        );
+
+        // localMinter, then getLocalToken, each once per call
+        let minter = Bytes::from(Address::repeat_byte(0x0d).into_word().to_vec());
+        let base_usdc = NamedChain::Base.usdc_address().unwrap();
+        asserter.push_success(&minter);
+        asserter.push_success(&Bytes::from(base_usdc.into_word().to_vec()));
+        bridge.verify_usdc_address().await.unwrap();
+
+        asserter.push_success(&minter);
+        asserter.push_success(&Bytes::from(B256::ZERO.to_vec()));
+        let err = bridge.verify_usdc_address().await.unwrap_err();
+        assert!(matches!(
+            err,
+            CctpError::UsdcAddressMismatch { expected, actual }
+                if expected == base_usdc && actual == Address::ZERO
+        ));
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_v2_check_burn_limit() {
+        let (asserter, provider) = mocked();
+        let bridge = CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .build();
+        let usdc = NamedChain::Mainnet.usdc_address().unwrap();
+        let minter = Address::repeat_byte(0x0d);
+        let limit = U256::from(1_000_000);
+        let push_limit = || {
+            // localMinter, then burnLimitsPerMessage
+            asserter.push_success(&Bytes::from(minter.into_word().to_vec()));
+            asserter.push_success(&Bytes::from(limit.to_be_bytes::<32>().to_vec()));
+        };
+
+        asserter.push_success(&Bytes::from(minter.into_word().to_vec()));
+        assert_eq!(bridge.local_minter().await.unwrap(), minter);
+
+        push_limit();
+        assert_eq!(bridge.burn_limit_per_message(usdc).await.unwrap(), limit);
+
+        push_limit();
+        bridge.check_burn_limit(limit, usdc).await.unwrap();
+
+        push_limit();
+        let err = bridge
+            .check_burn_limit(limit + U256::from(1), usdc)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CctpError::BurnLimitExceeded { amount, limit: reported }
+                if amount == limit + U256::from(1) && reported == limit
+        ));
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_v2_burn_with_permit_sends_back_to_back() {
+        use alloy_primitives::U64;
+
+        let (asserter, provider) = mocked();
+        let owner = Address::repeat_byte(0x01);
+        let usdc = NamedChain::Mainnet.usdc_address().unwrap();
+        let builder = || {
+            CctpV2::builder()
+                .source_chain(NamedChain::Mainnet)
+                .destination_chain(NamedChain::Base)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::ZERO)
+        };
+        let request = PermitRequest {
+            domain: usdc_eip712_domain("USD Coin", 1, usdc),
+            permit: Permit {
+                owner,
+                spender: NamedChain::Mainnet.token_messenger_v2_address().unwrap(),
+                value: U256::from(1_000_000),
+                nonce: U256::ZERO,
+                deadline: U256::MAX,
+            },
+        };
+        let signature = Signature::new(U256::from(1), U256::from(2), false);
+
+        // One pending-nonce read, then both sends, with no receipt wait
+        let bridge = builder().source_signer(owner).build();
+        asserter.push_success(&U64::from(7));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+        asserter.push_success(&TxHash::repeat_byte(0xa2));
+        let (permit_tx, burn_tx) = bridge.burn_with_permit(&request, &signature).await.unwrap();
+        assert_eq!(permit_tx, TxHash::repeat_byte(0xa1));
+        assert_eq!(burn_tx, TxHash::repeat_byte(0xa2));
+        assert!(asserter.read_q().is_empty());
+
+        // The nonce manager assigns the consecutive nonces instead
+        let manager = NonceManager::new();
+        let bridge = builder().nonce_manager(manager.clone()).build();
+        asserter.push_success(&U64::from(7));
+        asserter.push_success(&TxHash::repeat_byte(0xa1));
+        asserter.push_success(&TxHash::repeat_byte(0xa2));
+        bridge.burn_with_permit(&request, &signature).await.unwrap();
+        assert_eq!(manager.next_nonce(1, owner).await, Some(9));
+        assert!(asserter.read_q().is_empty());
+
+        // Only the owner can burn, so a different source signer is rejected
+        let bridge = builder().source_signer(Address::repeat_byte(0x02)).build();
+        let err = bridge
+            .burn_with_permit(&request, &signature)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+
+        let mut wrong_spender = request.clone();
+        wrong_spender.permit.spender = Address::repeat_byte(0x03);
+        let err = builder()
+            .build()
+            .burn_with_permit(&wrong_spender, &signature)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("spender")));
+    }
+
+    #[tokio::test]
+    async fn test_v2_try_build_validates_chains_and_providers() {
+        use alloy_primitives::U64;
+
+        let (asserter, provider) = mocked();
+        let build = |source: NamedChain, destination: NamedChain| {
+            CctpV2::builder()
+                .source_chain(source)
+                .destination_chain(destination)
+                .source_provider(provider.clone())
+                .destination_provider(provider.clone())
+                .recipient(Address::ZERO)
+                .try_build()
+        };
+
+        // Chain pair checks fail before any RPC call
+        let err = build(NamedChain::Base, NamedChain::Base).await.unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("both")));
+        let err = build(NamedChain::Mainnet, NamedChain::BaseSepolia)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.contains("testnet")));
+        let err = build(NamedChain::Moonbeam, NamedChain::Base)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CctpError::UnsupportedChain(NamedChain::Moonbeam)
+        ));
+        assert!(asserter.read_q().is_empty());
+
+        // Destination provider reports Mainnet instead of Base
+        asserter.push_success(&U64::from(1));
+        asserter.push_success(&U64::from(1));
+        let err = build(NamedChain::Mainnet, NamedChain::Base)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(msg) if msg.starts_with("destination")));
+
+        asserter.push_success(&U64::from(1));
+        asserter.push_success(&U64::from(8453));
+        let bridge = build(NamedChain::Mainnet, NamedChain::Base).await.unwrap();
+        assert_eq!(bridge.destination_chain().named(), Some(NamedChain::Base));
+    }
+
     #[test]
     fn test_v2_api_url_construction() {
         let provider =
@@ -1821,4 +3621,124 @@ mod tests {
         assert_eq!(bridge.hook_data().unwrap().len(), 4);
         assert_eq!(bridge.hook_data().unwrap()[0], 0xde);
     }
+
+    fn header_with_nonce(nonce: FixedBytes<32>) -> Vec<u8> {
+        MessageHeader::new(
+            1,
+            DomainId::Ethereum,
+            DomainId::Base,
+            nonce,
+            FixedBytes::ZERO,
+            FixedBytes::ZERO,
+            FixedBytes::ZERO,
+            2000,
+            2000,
+        )
+        .encode()
+        .to_vec()
+    }
+
+    fn bridge_on<P: Provider<Ethereum> + Clone>(provider: P) -> CctpV2<P> {
+        CctpV2::builder()
+            .source_chain(NamedChain::Mainnet)
+            .destination_chain(NamedChain::Base)
+            .source_provider(provider.clone())
+            .destination_provider(provider)
+            .recipient(Address::ZERO)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_is_message_received_rejects_placeholder_nonce() {
+        let bridge = bridge_on(
+            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap()),
+        );
+
+        let err = bridge
+            .is_message_received(&header_with_nonce(FixedBytes::ZERO))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CctpError::PlaceholderNonce));
+
+        let err = bridge.is_message_received(&[0u8; 16]).await.unwrap_err();
+        assert!(matches!(err, CctpError::InvalidMessage(_)));
+    }
+
+    /// Writes `1` to `usedNonces[nonce]` on the forked transmitter and returns
+    /// the declaration slot of the mapping. The slot is located through the
+    /// deployed contract's own `usedNonces` getter rather than assumed, so the
+    /// write lands at `keccak256(nonce ‖ slot)` exactly as `receiveMessage`
+    /// would leave it.
+    async fn mark_nonce_used<P: Provider<Ethereum>>(
+        provider: &P,
+        transmitter: Address,
+        nonce: FixedBytes<32>,
+    ) -> U256 {
+        use alloy_provider::ext::AnvilApi;
+        use alloy_sol_types::SolValue;
+
+        let contract = crate::contracts::v2::MessageTransmitterV2::new(transmitter, provider);
+        for slot in (0..64u64).map(U256::from) {
+            let key = alloy_primitives::keccak256((nonce, slot).abi_encode());
+            let previous = provider
+                .get_storage_at(transmitter, key.into())
+                .await
+                .unwrap();
+            provider
+                .anvil_set_storage_at(transmitter, key.into(), FixedBytes::with_last_byte(1))
+                .await
+                .unwrap();
+            if contract.usedNonces(nonce).call().await.unwrap() == U256::ONE {
+                return slot;
+            }
+            provider
+                .anvil_set_storage_at(transmitter, key.into(), previous.into())
+                .await
+                .unwrap();
+        }
+        panic!("usedNonces is not a mapping declared in the first 64 slots of {transmitter}");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil (foundry) on PATH and MAINNET_RPC_URL to fork"]
+    async fn test_is_message_received_keys_by_nonce_on_anvil() {
+        use alloy_provider::ext::AnvilApi;
+        use alloy_sol_types::SolValue;
+
+        let fork_url = std::env::var("MAINNET_RPC_URL").expect("MAINNET_RPC_URL must be set");
+        let provider =
+            ProviderBuilder::new().connect_anvil_with_config(|anvil| anvil.fork(fork_url));
+        let transmitter = crate::CCTP_V2_MESSAGE_TRANSMITTER_MAINNET;
+        let contract = crate::contracts::v2::MessageTransmitterV2::new(transmitter, &provider);
+
+        let bridge = bridge_on(provider.clone());
+        let nonce = FixedBytes::repeat_byte(0x42);
+        let message = header_with_nonce(nonce);
+        assert!(!bridge.is_message_received(&message).await.unwrap());
+
+        // The real transmitter keys usedNonces by the header nonce, so the
+        // entry for keccak256(message) must not count as received
+        let slot = mark_nonce_used(
+            &provider,
+            transmitter,
+            alloy_primitives::keccak256(&message),
+        )
+        .await;
+        assert!(!bridge.is_message_received(&message).await.unwrap());
+
+        let key = alloy_primitives::keccak256((nonce, slot).abi_encode());
+        provider
+            .anvil_set_storage_at(transmitter, key.into(), FixedBytes::with_last_byte(1))
+            .await
+            .unwrap();
+        assert_eq!(contract.usedNonces(nonce).call().await.unwrap(), U256::ONE);
+        assert!(bridge.is_message_received(&message).await.unwrap());
+
+        // mint_if_needed short-circuits before sending anything
+        let result = bridge
+            .mint_if_needed(message, vec![0u8; 65], Address::ZERO)
+            .await
+            .unwrap();
+        assert!(matches!(result, MintResult::AlreadyRelayed));
+    }
 }
@@ -0,0 +1,445 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! On-chain verification of the v2 chain tables.
+//!
+//! The contract addresses and domain ids the bridge uses come from static
+//! tables or a [`ChainRegistry`]. [`verify_chain_config`] reads the same
+//! values back from the deployed `MessageTransmitterV2` and
+//! `TokenMessengerV2` and reports every disagreement, so a wrong address,
+//! domain id or missing route is caught at start-up rather than on the first
+//! burn. [`verify_chain_record`] does the same for a registry record, such as
+//! a devnet or a chain in a custom environment.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use cctp_rs::verify_chain_config;
+//!
+//! let report = verify_chain_config(&provider, NamedChain::Base).await?;
+//! if !report.is_ok() {
+//!     for mismatch in &report.mismatches {
+//!         tracing::error!(%mismatch, "CCTP config mismatch on Base");
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use alloy_chains::Chain;
+use alloy_network::Network;
+use alloy_primitives::{Address, B256, U256};
+use alloy_provider::Provider;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::contracts::v2::{MessageTransmitterV2Contract, TokenMessengerV2Contract};
+use crate::error::{CctpError, Result};
+use crate::{
+    CctpEnvironment, CctpV2, ChainRecord, ChainRegistry, DomainId, CCTP_V2_MESSAGE_BODY_VERSION,
+    CCTP_V2_MESSAGE_VERSION,
+};
+
+/// On-chain v2 configuration of a chain, with its differences from the
+/// expected configuration
+///
+/// Returned by [`verify_chain_config`] and [`verify_chain_record`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainConfigReport {
+    /// Chain that was checked
+    pub chain: Chain,
+    /// `TokenMessengerV2` address that was read
+    pub token_messenger: Address,
+    /// `MessageTransmitterV2` address that was read
+    pub message_transmitter: Address,
+    /// Attester signatures a message needs on this chain
+    pub signature_threshold: U256,
+    /// Largest message body, in bytes, this chain will send
+    pub max_message_body_size: U256,
+    /// Every value that disagrees with the expected configuration
+    pub mismatches: Vec<ConfigMismatch>,
+}
+
+impl ChainConfigReport {
+    /// Whether the deployed contracts match the expected configuration
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// One on-chain value that disagrees with the expected configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "field", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ConfigMismatch {
+    /// `MessageTransmitterV2.localDomain` is not the chain's domain id
+    LocalDomain { expected: DomainId, actual: u32 },
+    /// `MessageTransmitterV2.version` is not the v2 message version
+    MessageVersion { expected: u32, actual: u32 },
+    /// `TokenMessengerV2.messageBodyVersion` is not the v2 body version
+    MessageBodyVersion { expected: u32, actual: u32 },
+    /// `TokenMessengerV2.localMessageTransmitter` is not the expected
+    /// transmitter address
+    LocalMessageTransmitter { expected: Address, actual: Address },
+    /// `TokenMessengerV2.remoteTokenMessengers(domain)` is not the remote
+    /// chain's messenger; zero means the route is not linked
+    RemoteTokenMessenger {
+        domain: DomainId,
+        expected: B256,
+        actual: B256,
+    },
+    /// `MessageTransmitterV2.signatureThreshold` is zero
+    SignatureThreshold { actual: U256 },
+}
+
+impl fmt::Display for ConfigMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LocalDomain { expected, actual } => {
+                write!(f, "localDomain is {actual}, expected {expected}")
+            }
+            Self::MessageVersion { expected, actual } => {
+                write!(f, "version is {actual}, expected {expected}")
+            }
+            Self::MessageBodyVersion { expected, actual } => {
+                write!(f, "messageBodyVersion is {actual}, expected {expected}")
+            }
+            Self::LocalMessageTransmitter { expected, actual } => {
+                write!(
+                    f,
+                    "localMessageTransmitter is {actual}, expected {expected}"
+                )
+            }
+            Self::RemoteTokenMessenger {
+                domain,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "remoteTokenMessengers({domain}) is {actual}, expected {expected}"
+            ),
+            Self::SignatureThreshold { actual } => {
+                write!(f, "signatureThreshold is {actual}, expected at least 1")
+            }
+        }
+    }
+}
+
+/// Read `chain`'s v2 configuration from its contracts and compare it with
+/// the built-in tables
+///
+/// `chain` is a `NamedChain` or a chain id known to
+/// [`ChainRegistry::builtin`], such as Codex. This is
+/// [`verify_chain_record`] with the built-in record and registry.
+///
+/// # Errors
+///
+/// Returns an error if `chain` does not support CCTP v2 or a read fails.
+/// Disagreements are not errors; they are listed in
+/// [`ChainConfigReport::mismatches`].
+pub async fn verify_chain_config<P, N>(
+    provider: &P,
+    chain: impl Into<Chain>,
+) -> Result<ChainConfigReport>
+where
+    P: Provider<N> + Clone,
+    N: Network,
+{
+    let chain = chain.into();
+    let record = ChainRecord::from_chain_id(chain.id()).ok_or(match chain.named() {
+        Some(named) => CctpError::UnsupportedChain(named),
+        None => CctpError::UnsupportedChainId(chain.id()),
+    })?;
+    verify_chain_record(provider, &record, &ChainRegistry::builtin()).await
+}
+
+/// Read the v2 configuration of `record`'s chain from its contracts and
+/// compare it with `record` and `registry`
+///
+/// `provider` must be connected to the chain. Checks `localDomain`,
+/// `version`, `signatureThreshold` and `maxMessageBodySize` on the
+/// `MessageTransmitterV2`, and `messageBodyVersion`,
+/// `localMessageTransmitter` and `remoteTokenMessengers` on the
+/// `TokenMessengerV2`. Remote messengers are checked for every other
+/// domain with a v2 record in `registry` in the same environment, so
+/// chains in a custom environment are checked against each other.
+///
+/// # Errors
+///
+/// Returns an error if `record` has no v2 deployment or a read fails.
+/// Disagreements are not errors; they are listed in
+/// [`ChainConfigReport::mismatches`].
+pub async fn verify_chain_record<P, N>(
+    provider: &P,
+    record: &ChainRecord,
+    registry: &ChainRegistry,
+) -> Result<ChainConfigReport>
+where
+    P: Provider<N> + Clone,
+    N: Network,
+{
+    let chain = Chain::from_id(record.chain_id);
+    let domain = record.cctp_v2_domain_id()?;
+    let token_messenger_address = record.token_messenger_v2_address()?;
+    let message_transmitter_address = record.message_transmitter_v2_address()?;
+    let message_transmitter =
+        MessageTransmitterV2Contract::new(message_transmitter_address, provider.clone());
+    let token_messenger = TokenMessengerV2Contract::new(token_messenger_address, provider.clone());
+
+    let mut mismatches = Vec::new();
+
+    let local_domain = message_transmitter.local_domain().await?;
+    if local_domain != domain.as_u32() {
+        mismatches.push(ConfigMismatch::LocalDomain {
+            expected: domain,
+            actual: local_domain,
+        });
+    }
+
+    let version = message_transmitter.version().await?;
+    if version != CCTP_V2_MESSAGE_VERSION {
+        mismatches.push(ConfigMismatch::MessageVersion {
+            expected: CCTP_V2_MESSAGE_VERSION,
+            actual: version,
+        });
+    }
+
+    let signature_threshold = message_transmitter.signature_threshold().await?;
+    if signature_threshold.is_zero() {
+        mismatches.push(ConfigMismatch::SignatureThreshold {
+            actual: signature_threshold,
+        });
+    }
+    let max_message_body_size = message_transmitter.max_message_body_size().await?;
+
+    let body_version = token_messenger.message_body_version().await?;
+    if body_version != CCTP_V2_MESSAGE_BODY_VERSION {
+        mismatches.push(ConfigMismatch::MessageBodyVersion {
+            expected: CCTP_V2_MESSAGE_BODY_VERSION,
+            actual: body_version,
+        });
+    }
+
+    let local_message_transmitter = token_messenger.local_message_transmitter().await?;
+    if local_message_transmitter != message_transmitter_address {
+        mismatches.push(ConfigMismatch::LocalMessageTransmitter {
+            expected: message_transmitter_address,
+            actual: local_message_transmitter,
+        });
+    }
+
+    for (remote_domain, expected) in remote_token_messengers(registry, &record.environment, domain)
+    {
+        let actual = token_messenger
+            .remote_token_messenger(remote_domain)
+            .await?;
+        if actual != expected {
+            mismatches.push(ConfigMismatch::RemoteTokenMessenger {
+                domain: remote_domain,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    if mismatches.is_empty() {
+        info!(
+            chain = %chain,
+            version = "v2",
+            event = "chain_config_verified"
+        );
+    } else {
+        warn!(
+            chain = %chain,
+            mismatch_count = mismatches.len(),
+            version = "v2",
+            event = "chain_config_mismatch"
+        );
+    }
+
+    Ok(ChainConfigReport {
+        chain,
+        token_messenger: token_messenger_address,
+        message_transmitter: message_transmitter_address,
+        signature_threshold,
+        max_message_body_size,
+        mismatches,
+    })
+}
+
+/// Expected remote messenger of every other domain in `environment` with a
+/// v2 record in `registry`, in domain order
+fn remote_token_messengers(
+    registry: &ChainRegistry,
+    environment: &CctpEnvironment,
+    local: DomainId,
+) -> Vec<(DomainId, B256)> {
+    let mut remotes = BTreeMap::new();
+    for record in registry.records() {
+        if record.environment != *environment || record.domain == local {
+            continue;
+        }
+        if let Ok(messenger) = record.token_messenger_v2_address() {
+            remotes.insert(
+                record.domain.as_u32(),
+                (record.domain, messenger.into_word()),
+            );
+        }
+    }
+    remotes.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mocked, word};
+    use alloy_chains::NamedChain;
+    use alloy_network::Ethereum;
+
+    fn builtin_remotes(chain: NamedChain, local: DomainId) -> Vec<(DomainId, B256)> {
+        remote_token_messengers(&ChainRegistry::builtin(), &chain.environment(), local)
+    }
+
+    #[test]
+    fn test_remote_domains_stay_in_environment() {
+        let remotes = builtin_remotes(NamedChain::Base, DomainId::Base);
+        let domains: Vec<_> = remotes.iter().map(|(domain, _)| *domain).collect();
+        assert!(domains.contains(&DomainId::Ethereum));
+        assert!(domains.contains(&DomainId::Codex));
+        assert!(!domains.contains(&DomainId::Base));
+        assert!(!domains.contains(&DomainId::ArcTestnet));
+
+        let testnet = builtin_remotes(NamedChain::BaseSepolia, DomainId::Base);
+        assert!(testnet
+            .iter()
+            .any(|(domain, _)| *domain == DomainId::ArcTestnet));
+    }
+
+    #[tokio::test]
+    async fn test_reports_mismatches() {
+        let (asserter, provider) = mocked();
+
+        let chain = NamedChain::Base;
+        let remotes = builtin_remotes(chain, DomainId::Base);
+        asserter.push_success(&word(6u32)); // localDomain
+        asserter.push_success(&word(2u32)); // version
+        asserter.push_success(&word(U256::from(2))); // signatureThreshold
+        asserter.push_success(&word(U256::from(8192))); // maxMessageBodySize
+        asserter.push_success(&word(1u32)); // messageBodyVersion
+        asserter.push_success(&word(chain.message_transmitter_v2_address().unwrap()));
+        for (index, (_, messenger)) in remotes.iter().enumerate() {
+            // Every route but the first is linked
+            let linked = if index == 0 { B256::ZERO } else { *messenger };
+            asserter.push_success(&word(linked));
+        }
+
+        let report = verify_chain_config::<_, Ethereum>(&provider, chain)
+            .await
+            .unwrap();
+        assert!(asserter.read_q().is_empty());
+
+        assert_eq!(report.max_message_body_size, U256::from(8192));
+        assert_eq!(
+            report.mismatches,
+            vec![
+                ConfigMismatch::MessageVersion {
+                    expected: 1,
+                    actual: 2
+                },
+                ConfigMismatch::RemoteTokenMessenger {
+                    domain: DomainId::Ethereum,
+                    expected: remotes[0].1,
+                    actual: B256::ZERO,
+                },
+            ]
+        );
+        assert!(!report.is_ok());
+        assert_eq!(report.mismatches[0].to_string(), "version is 2, expected 1");
+    }
+
+    #[tokio::test]
+    async fn test_verifies_custom_environment_records() {
+        let (asserter, provider) = mocked();
+
+        let staging = CctpEnvironment::Custom {
+            iris_api: "http://localhost:8080".parse().unwrap(),
+            token_messenger: Address::repeat_byte(0x01),
+            message_transmitter: Address::repeat_byte(0x02),
+        };
+        let record = |chain_id, domain| ChainRecord {
+            chain_id,
+            domain,
+            environment: staging.clone(),
+            usdc: None,
+            v1: None,
+            v2: Some(crate::V2Deployment {
+                token_messenger: staging.token_messenger_v2_address(),
+                message_transmitter: staging.message_transmitter_v2_address(),
+                fast_transfer_seconds: 2,
+                standard_transfer_seconds: 2,
+                fast_transfer: true,
+                fast_transfer_fee_bps: None,
+            }),
+        };
+        let mut registry = ChainRegistry::builtin();
+        registry.insert(record(900_001, DomainId::Ethereum));
+        registry.insert(record(900_002, DomainId::Base));
+
+        asserter.push_success(&word(0u32)); // localDomain
+        asserter.push_success(&word(1u32)); // version
+        asserter.push_success(&word(U256::from(1))); // signatureThreshold
+        asserter.push_success(&word(U256::from(8192))); // maxMessageBodySize
+        asserter.push_success(&word(1u32)); // messageBodyVersion
+        asserter.push_success(&word(Address::repeat_byte(0x02)));
+        // Only the other staging chain is a remote, not the built-in chains
+        asserter.push_success(&word(Address::repeat_byte(0x01).into_word()));
+
+        let report = verify_chain_record::<_, Ethereum>(
+            &provider,
+            registry.get(900_001).unwrap(),
+            &registry,
+        )
+        .await
+        .unwrap();
+        assert!(asserter.read_q().is_empty());
+        assert!(report.is_ok());
+        assert_eq!(report.chain.id(), 900_001);
+        assert_eq!(report.token_messenger, Address::repeat_byte(0x01));
+    }
+
+    #[tokio::test]
+    async fn test_verifies_codex_by_chain_id() {
+        let (asserter, provider) = mocked();
+
+        let codex = ChainRecord::from_chain_id(81224).unwrap();
+        let remotes = remote_token_messengers(
+            &ChainRegistry::builtin(),
+            &CctpEnvironment::Mainnet,
+            DomainId::Codex,
+        );
+        asserter.push_success(&word(DomainId::Codex.as_u32())); // localDomain
+        asserter.push_success(&word(1u32)); // version
+        asserter.push_success(&word(U256::from(2))); // signatureThreshold
+        asserter.push_success(&word(U256::from(8192))); // maxMessageBodySize
+        asserter.push_success(&word(1u32)); // messageBodyVersion
+        asserter.push_success(&word(codex.message_transmitter_v2_address().unwrap()));
+        for (_, messenger) in &remotes {
+            asserter.push_success(&word(*messenger));
+        }
+
+        let report = verify_chain_config::<_, Ethereum>(&provider, 81224)
+            .await
+            .unwrap();
+        assert!(asserter.read_q().is_empty());
+        assert!(report.is_ok());
+        assert_eq!(report.chain, Chain::from_id(81224));
+
+        let unknown = verify_chain_config::<_, Ethereum>(&provider, 900_001).await;
+        assert!(matches!(
+            unknown,
+            Err(CctpError::UnsupportedChainId(900_001))
+        ));
+    }
+}
@@ -145,3 +145,129 @@ pub const CCTP_V2_MESSAGE_TRANSMITTER_TESTNET: Address =
 /// <https://developers.circle.com/cctp/evm-smart-contracts>
 pub const CCTP_V2_TOKEN_MESSENGER_TESTNET: Address =
     address!("8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA");
+
+// =============================================================================
+// Native USDC Token Addresses
+// =============================================================================
+//
+// Canonical Circle-issued USDC, the token CCTP burns and mints. Bridged
+// variants such as USDC.e are not listed.
+//
+// Reference: <https://developers.circle.com/stablecoins/usdc-contract-addresses>
+
+/// Native USDC on Ethereum
+pub const ETHEREUM_USDC_ADDRESS: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+
+/// Native USDC on Avalanche C-Chain
+pub const AVALANCHE_USDC_ADDRESS: Address = address!("B97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E");
+
+/// Native USDC on OP Mainnet
+pub const OPTIMISM_USDC_ADDRESS: Address = address!("0b2C639c533813f4Aa9D7837CAf62653d097Ff85");
+
+/// Native USDC on Arbitrum One
+pub const ARBITRUM_USDC_ADDRESS: Address = address!("af88d065e77c8cC2239327C5EDb3A432268e5831");
+
+/// Native USDC on Base
+pub const BASE_USDC_ADDRESS: Address = address!("833589fCD6eDb6E08f4c7C32D4f71b54bdA02913");
+
+/// Native USDC on Polygon `PoS`
+pub const POLYGON_USDC_ADDRESS: Address = address!("3c499c542cEF5E3811e1192ce70d8cC03d5c3359");
+
+/// Native USDC on Unichain
+pub const UNICHAIN_USDC_ADDRESS: Address = address!("078D782b760474a361dDA0AF3839290b0EF57AD6");
+
+/// Native USDC on Linea
+pub const LINEA_USDC_ADDRESS: Address = address!("176211869cA2b568f2A7D4EE941E073a821EE1ff");
+
+/// Native USDC on Codex
+pub const CODEX_USDC_ADDRESS: Address = address!("d996633a415985DBd7D6D12f4A4343E31f5037cf");
+
+/// Native USDC on Sonic
+pub const SONIC_USDC_ADDRESS: Address = address!("29219dd400f2Bf60E5a23d13Be72B486D4038894");
+
+/// Native USDC on World Chain
+pub const WORLD_CHAIN_USDC_ADDRESS: Address = address!("79A02482A880bCE3F13e09Da970dC34db4CD24d1");
+
+/// Native USDC on Monad
+pub const MONAD_USDC_ADDRESS: Address = address!("754704Bc059F8C67012fEd69BC8A327a5aafb603");
+
+/// Native USDC on Sei
+pub const SEI_USDC_ADDRESS: Address = address!("e15fC38F6D8c56aF07bbCBe3BAf5708A2Bf42392");
+
+/// Native USDC on XDC
+pub const XDC_USDC_ADDRESS: Address = address!("fA2958CB79b0491CC627c1557F441eF849Ca8eb1");
+
+/// Native USDC on `HyperEVM`
+pub const HYPEREVM_USDC_ADDRESS: Address = address!("b88339CB7199b77E23DB6E890353E22632Ba630f");
+
+/// Native USDC on Ink
+pub const INK_USDC_ADDRESS: Address = address!("2D270e6886d130D724215A266106e6832161EAEd");
+
+/// Native USDC on Plume
+pub const PLUME_USDC_ADDRESS: Address = address!("222365EF19F7947e5484218551B56bb3965Aa7aF");
+
+/// Native USDC on Sepolia
+pub const ETHEREUM_SEPOLIA_USDC_ADDRESS: Address =
+    address!("1c7D4B196Cb0C7B01d743Fbc6116a902379C7238");
+
+/// Native USDC on Avalanche Fuji
+pub const AVALANCHE_FUJI_USDC_ADDRESS: Address =
+    address!("5425890298aed601595a70AB815c96711a31Bc65");
+
+/// Native USDC on OP Sepolia
+pub const OPTIMISM_SEPOLIA_USDC_ADDRESS: Address =
+    address!("5fd84259d66Cd46123540766Be93DFE6D43130D7");
+
+/// Native USDC on Arbitrum Sepolia
+pub const ARBITRUM_SEPOLIA_USDC_ADDRESS: Address =
+    address!("75faf114eafb1BDbe2F0316DF893fd58CE46AA4d");
+
+/// Native USDC on Base Sepolia
+pub const BASE_SEPOLIA_USDC_ADDRESS: Address = address!("036CbD53842c5426634e7929541eC2318f3dCF7e");
+
+/// Native USDC on Polygon Amoy
+pub const POLYGON_AMOY_USDC_ADDRESS: Address = address!("41E94Eb019C0762f9Bfcf9Fb1E58725BfB0e7582");
+
+/// Native USDC on Unichain Sepolia
+pub const UNICHAIN_SEPOLIA_USDC_ADDRESS: Address =
+    address!("31d0220469e10c4E71834a79b1f276d740d3768F");
+
+/// Native USDC on Linea Sepolia
+pub const LINEA_SEPOLIA_USDC_ADDRESS: Address =
+    address!("FEce4462D57bD51A6A552365A011b95f0E16d9B7");
+
+/// Native USDC on Codex Testnet
+pub const CODEX_TESTNET_USDC_ADDRESS: Address =
+    address!("6d7f141b6819C2c9CC2f818e6ad549E7Ca090F8f");
+
+/// Native USDC on Sonic Testnet
+pub const SONIC_TESTNET_USDC_ADDRESS: Address =
+    address!("A4879Fed32Ecbef99399e5cbC247E533421C4eC6");
+
+/// Native USDC on World Chain Sepolia
+pub const WORLD_CHAIN_SEPOLIA_USDC_ADDRESS: Address =
+    address!("66145f38cBAC35Ca6F1Dfb4914dF98F1614aeA88");
+
+/// Native USDC on Monad Testnet
+pub const MONAD_TESTNET_USDC_ADDRESS: Address =
+    address!("534b2f3A21130d7a60830c2Df862319e593943A3");
+
+/// Native USDC on Sei Testnet
+pub const SEI_TESTNET_USDC_ADDRESS: Address = address!("4fCF1784B31630811181f670Aea7A7bEF803eaED");
+
+/// Native USDC on XDC Apothem
+pub const XDC_APOTHEM_USDC_ADDRESS: Address = address!("b5AB69F7bBada22B28e79C8FFAECe55eF1c771D4");
+
+/// Native USDC on `HyperEVM` Testnet
+pub const HYPEREVM_TESTNET_USDC_ADDRESS: Address =
+    address!("2B3370eE501B4a559b57D449569354196457D8Ab");
+
+/// Native USDC on Ink Sepolia
+pub const INK_SEPOLIA_USDC_ADDRESS: Address = address!("FabAb97dCE620294D2B0b0e46C68964e326300Ac");
+
+/// Native USDC on Plume Testnet
+pub const PLUME_TESTNET_USDC_ADDRESS: Address =
+    address!("cB5f30e335672893c7eb944B374c196392C19D18");
+
+/// Native USDC on Arc Testnet
+pub const ARC_TESTNET_USDC_ADDRESS: Address = address!("3600000000000000000000000000000000000000");
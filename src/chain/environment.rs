@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! CCTP attestation environments
+
+use std::fmt;
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::addresses::{
+    CCTP_V2_MESSAGE_TRANSMITTER_MAINNET, CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
+    CCTP_V2_TOKEN_MESSENGER_MAINNET, CCTP_V2_TOKEN_MESSENGER_TESTNET,
+};
+use crate::bridge::{IRIS_API, IRIS_API_SANDBOX};
+
+/// The Iris API and v2 contracts a chain attests and mints through
+///
+/// Circle runs two environments, each with one Iris API and one pair of
+/// v2 contract addresses shared by every chain in it. `Custom` describes
+/// anything else: a staging deployment, or a local fork of mainnet served
+/// by a mock attestation service, which would otherwise be sent to the
+/// production Iris API.
+///
+/// Every chain reports its environment through [`CctpV2::environment`],
+/// and a bridge can be given one explicitly with `.environment(..)`.
+///
+/// [`CctpV2::environment`]: super::CctpV2::environment
+///
+/// # Example
+///
+/// ```rust
+/// use alloy_chains::NamedChain;
+/// use cctp_rs::{CctpEnvironment, CctpV2};
+///
+/// assert_eq!(NamedChain::Base.environment(), CctpEnvironment::Mainnet);
+/// assert_eq!(NamedChain::BaseSepolia.environment(), CctpEnvironment::Testnet);
+/// assert_eq!(
+///     CctpEnvironment::Testnet.iris_api_url().as_str(),
+///     "https://iris-api-sandbox.circle.com/",
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CctpEnvironment {
+    /// Circle's production environment
+    #[default]
+    Mainnet,
+    /// Circle's sandbox environment, shared by every testnet
+    Testnet,
+    /// A self-hosted or staging environment
+    Custom {
+        /// Iris API base URL
+        iris_api: Url,
+        /// `TokenMessengerV2` address shared by the environment's chains
+        token_messenger: Address,
+        /// `MessageTransmitterV2` address shared by the environment's chains
+        message_transmitter: Address,
+    },
+}
+
+impl CctpEnvironment {
+    /// Iris API base URL of this environment
+    pub fn iris_api_url(&self) -> Url {
+        match self {
+            Self::Mainnet => Url::parse(IRIS_API).unwrap(),
+            Self::Testnet => Url::parse(IRIS_API_SANDBOX).unwrap(),
+            Self::Custom { iris_api, .. } => iris_api.clone(),
+        }
+    }
+
+    /// `TokenMessengerV2` address shared by every chain in this environment
+    pub fn token_messenger_v2_address(&self) -> Address {
+        match self {
+            Self::Mainnet => CCTP_V2_TOKEN_MESSENGER_MAINNET,
+            Self::Testnet => CCTP_V2_TOKEN_MESSENGER_TESTNET,
+            Self::Custom {
+                token_messenger, ..
+            } => *token_messenger,
+        }
+    }
+
+    /// `MessageTransmitterV2` address shared by every chain in this environment
+    pub fn message_transmitter_v2_address(&self) -> Address {
+        match self {
+            Self::Mainnet => CCTP_V2_MESSAGE_TRANSMITTER_MAINNET,
+            Self::Testnet => CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
+            Self::Custom {
+                message_transmitter,
+                ..
+            } => *message_transmitter,
+        }
+    }
+}
+
+impl fmt::Display for CctpEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mainnet => f.write_str("mainnet"),
+            Self::Testnet => f.write_str("testnet"),
+            Self::Custom { iris_api, .. } => write!(f, "custom environment at {iris_api}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staging() -> CctpEnvironment {
+        CctpEnvironment::Custom {
+            iris_api: Url::parse("http://localhost:8080").unwrap(),
+            token_messenger: Address::repeat_byte(0x01),
+            message_transmitter: Address::repeat_byte(0x02),
+        }
+    }
+
+    #[test]
+    fn test_environment_endpoints_and_contracts() {
+        assert_eq!(
+            CctpEnvironment::Mainnet.iris_api_url(),
+            Url::parse(IRIS_API).unwrap()
+        );
+        assert_eq!(
+            CctpEnvironment::Testnet.token_messenger_v2_address(),
+            CCTP_V2_TOKEN_MESSENGER_TESTNET
+        );
+
+        let staging = staging();
+        assert_eq!(staging.iris_api_url().as_str(), "http://localhost:8080/");
+        assert_eq!(
+            staging.token_messenger_v2_address(),
+            Address::repeat_byte(0x01)
+        );
+        assert_eq!(
+            staging.message_transmitter_v2_address(),
+            Address::repeat_byte(0x02)
+        );
+        assert_eq!(
+            staging.to_string(),
+            "custom environment at http://localhost:8080/"
+        );
+    }
+
+    #[test]
+    fn test_environment_serde() {
+        assert_eq!(
+            serde_json::to_string(&CctpEnvironment::Testnet).unwrap(),
+            r#""testnet""#
+        );
+
+        let json = serde_json::to_string(&staging()).unwrap();
+        assert!(json.starts_with(r#"{"custom":{"iris_api":"http://localhost:8080/""#));
+        let parsed: CctpEnvironment = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, staging());
+    }
+}
@@ -0,0 +1,443 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Route capability matrix
+
+use std::fmt::Write as _;
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::{CctpEnvironment, CctpV1, CctpV2, ChainRecord, ChainRegistry};
+use crate::bridge::FEES_PATH_V2;
+use crate::error::{CctpError, Result};
+use crate::protocol::{DomainId, FinalityThreshold};
+
+/// Column names of [`RouteMatrix::to_csv`]
+const CSV_HEADER: &str = "source_chain_id,source_domain,destination_chain_id,\
+                          destination_domain,environment,version,fast_transfer,\
+                          fast_eta_seconds,standard_eta_seconds,fast_transfer_fee_bps,hooks";
+
+/// What one CCTP version offers on one route
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteCapability {
+    /// Chain id of the chain USDC is burned on
+    pub source_chain_id: u64,
+    /// CCTP domain of the source chain
+    pub source_domain: DomainId,
+    /// Chain id of the chain USDC is minted on
+    pub destination_chain_id: u64,
+    /// CCTP domain of the destination chain
+    pub destination_domain: DomainId,
+    /// Environment both chains belong to
+    pub environment: CctpEnvironment,
+    /// CCTP version, 1 or 2
+    pub version: u8,
+    /// Whether Fast Transfer is available
+    pub fast_transfer: bool,
+    /// Expected Fast Transfer attestation time, in seconds
+    pub fast_eta_seconds: Option<u64>,
+    /// Expected Standard Transfer attestation time, in seconds
+    pub standard_eta_seconds: u64,
+    /// Fast Transfer fee in basis points, if known
+    ///
+    /// Taken from the source chain's registry record when it has one.
+    /// Circle sets the fee per `(source, destination)` pair and publishes
+    /// it through the Iris API, so the built-in records leave it unset;
+    /// [`RouteMatrix::with_fees`] fetches the current value.
+    pub fast_transfer_fee_bps: Option<u32>,
+    /// Whether burns can carry hook data
+    pub hooks: bool,
+}
+
+impl RouteCapability {
+    fn v1(source: &ChainRecord, destination: &ChainRecord) -> Option<Self> {
+        Some(Self {
+            version: 1,
+            fast_transfer: false,
+            fast_eta_seconds: None,
+            standard_eta_seconds: source.confirmation_average_time_seconds().ok()?,
+            fast_transfer_fee_bps: None,
+            hooks: false,
+            ..Self::endpoints(source, destination)
+        })
+    }
+
+    fn v2(source: &ChainRecord, destination: &ChainRecord) -> Option<Self> {
+        let fast_transfer = source.supports_fast_transfer().ok()?;
+        Some(Self {
+            version: 2,
+            fast_transfer,
+            fast_eta_seconds: fast_transfer
+                .then(|| source.fast_transfer_confirmation_time_seconds().ok())
+                .flatten(),
+            standard_eta_seconds: source.standard_transfer_confirmation_time_seconds().ok()?,
+            fast_transfer_fee_bps: fast_transfer
+                .then(|| source.fast_transfer_fee_bps().ok().flatten())
+                .flatten(),
+            hooks: true,
+            ..Self::endpoints(source, destination)
+        })
+    }
+
+    /// A row for the pair with every capability unset
+    fn endpoints(source: &ChainRecord, destination: &ChainRecord) -> Self {
+        Self {
+            source_chain_id: source.chain_id,
+            source_domain: source.domain,
+            destination_chain_id: destination.chain_id,
+            destination_domain: destination.domain,
+            environment: source.environment.clone(),
+            version: 0,
+            fast_transfer: false,
+            fast_eta_seconds: None,
+            standard_eta_seconds: 0,
+            fast_transfer_fee_bps: None,
+            hooks: false,
+        }
+    }
+}
+
+/// Capabilities of every supported route, per CCTP version
+///
+/// Built from the same [`CctpV1`] and [`CctpV2`] tables the bridges use, so
+/// routing services and documentation can export it instead of keeping
+/// their own copy. A route served by both versions has one row for each.
+/// Attestation times are those of the source chain, which decides them.
+/// Fast Transfer fees come from the registry records, when set, or from
+/// Iris through [`RouteMatrix::with_fees`].
+///
+/// # Example
+///
+/// ```rust
+/// use cctp_rs::RouteMatrix;
+///
+/// let matrix = RouteMatrix::builtin();
+/// let csv = matrix.to_csv();
+/// assert!(csv.starts_with("source_chain_id,source_domain,"));
+/// assert_eq!(csv.lines().count(), matrix.routes.len() + 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteMatrix {
+    /// One row per route and version, ordered by source, then destination
+    /// chain id, then version
+    pub routes: Vec<RouteCapability>,
+}
+
+impl RouteMatrix {
+    /// Build the matrix of every route between chains in `registry`
+    pub fn from_registry(registry: &ChainRegistry) -> Self {
+        let mut routes = Vec::new();
+        for route in registry.routes() {
+            let (Some(source), Some(destination)) = (
+                registry.get(route.source_chain_id),
+                registry.get(route.destination_chain_id),
+            ) else {
+                continue;
+            };
+            if route.v1 {
+                routes.extend(RouteCapability::v1(source, destination));
+            }
+            if route.v2 {
+                routes.extend(RouteCapability::v2(source, destination));
+            }
+        }
+        Self { routes }
+    }
+
+    /// Build the matrix of every chain built into this crate
+    pub fn builtin() -> Self {
+        Self::from_registry(&ChainRegistry::builtin())
+    }
+
+    /// Fill in the Fast Transfer fee of every v2 route from the Iris API
+    ///
+    /// Sends one `GET /v2/burn/USDC/fees/{source}/{destination}` per v2
+    /// route with Fast Transfer, to the Iris API of the route's
+    /// environment, and replaces
+    /// [`fast_transfer_fee_bps`](RouteCapability::fast_transfer_fee_bps)
+    /// with the minimum fee Iris quotes for Fast Transfer, rounded up to a
+    /// whole basis point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError::Network`] if a request fails or Iris answers
+    /// with an error status, and [`CctpError::InvalidConfig`] if Iris quotes
+    /// no Fast Transfer fee for a route.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let matrix = RouteMatrix::builtin().with_fees(&reqwest::Client::new()).await?;
+    /// ```
+    pub async fn with_fees(mut self, client: &Client) -> Result<Self> {
+        for route in &mut self.routes {
+            if route.version != 2 || !route.fast_transfer {
+                continue;
+            }
+            let url = route.environment.iris_api_url().join(&format!(
+                "{FEES_PATH_V2}{}/{}",
+                route.source_domain.as_u32(),
+                route.destination_domain.as_u32()
+            ))?;
+            let fees: Vec<IrisFee> = client
+                .get(url.as_str())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(CctpError::Network)?
+                .json()
+                .await
+                .map_err(CctpError::Network)?;
+            route.fast_transfer_fee_bps = Some(fast_fee_bps(&fees).ok_or_else(|| {
+                CctpError::InvalidConfig(format!(
+                    "Iris quotes no Fast Transfer fee from {} to {}",
+                    route.source_domain, route.destination_domain
+                ))
+            })?);
+        }
+        Ok(self)
+    }
+
+    /// Render the matrix as JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Render the matrix as CSV with a header row
+    ///
+    /// Domains are written as their numeric ids, environments as `mainnet`,
+    /// `testnet` or the custom Iris API URL, and unknown values as empty
+    /// fields.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for route in &self.routes {
+            let environment = match &route.environment {
+                CctpEnvironment::Custom { iris_api, .. } => csv_field(iris_api.as_str()),
+                environment => environment.to_string(),
+            };
+            let optional = |value: Option<String>| value.unwrap_or_default();
+            // Writing to a String cannot fail
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                route.source_chain_id,
+                route.source_domain.as_u32(),
+                route.destination_chain_id,
+                route.destination_domain.as_u32(),
+                environment,
+                route.version,
+                route.fast_transfer,
+                optional(route.fast_eta_seconds.map(|seconds| seconds.to_string())),
+                route.standard_eta_seconds,
+                optional(route.fast_transfer_fee_bps.map(|bps| bps.to_string())),
+                route.hooks,
+            );
+        }
+        csv
+    }
+}
+
+/// One entry of the Iris fees response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IrisFee {
+    finality_threshold: u32,
+    /// Minimum fee in basis points, possibly fractional
+    minimum_fee: f64,
+}
+
+/// The Fast Transfer fee among `fees`, rounded up to a whole basis point
+fn fast_fee_bps(fees: &[IrisFee]) -> Option<u32> {
+    fees.iter()
+        .find(|fee| fee.finality_threshold <= FinalityThreshold::Fast.as_u32())
+        .map(|fee| fee.minimum_fee.max(0.0).ceil() as u32)
+}
+
+/// Quote a CSV field that contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_chains::NamedChain;
+
+    use super::*;
+
+    fn row(matrix: &RouteMatrix, source: u64, destination: u64, version: u8) -> &RouteCapability {
+        matrix
+            .routes
+            .iter()
+            .find(|route| {
+                route.source_chain_id == source
+                    && route.destination_chain_id == destination
+                    && route.version == version
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_builtin_matrix_rows() {
+        let matrix = RouteMatrix::builtin();
+        let (mainnet, arbitrum, linea) = (
+            u64::from(NamedChain::Mainnet),
+            u64::from(NamedChain::Arbitrum),
+            u64::from(NamedChain::Linea),
+        );
+
+        let v1 = row(&matrix, mainnet, arbitrum, 1);
+        assert!(!v1.fast_transfer && !v1.hooks);
+        assert_eq!(v1.standard_eta_seconds, 19 * 60);
+        assert_eq!(v1.fast_eta_seconds, None);
+
+        let v2 = row(&matrix, mainnet, arbitrum, 2);
+        assert!(v2.fast_transfer && v2.hooks);
+        assert_eq!(
+            v2.fast_eta_seconds,
+            NamedChain::Mainnet
+                .fast_transfer_confirmation_time_seconds()
+                .ok()
+        );
+        assert!(matrix
+            .routes
+            .iter()
+            .all(|route| route.fast_transfer_fee_bps.is_none()));
+
+        assert!(matrix
+            .routes
+            .iter()
+            .all(|route| !(route.source_chain_id == mainnet
+                && route.destination_chain_id == linea
+                && route.version == 1)));
+        assert!(matrix.routes.windows(2).all(|pair| (
+            pair[0].source_chain_id,
+            pair[0].destination_chain_id,
+            pair[0].version
+        ) < (
+            pair[1].source_chain_id,
+            pair[1].destination_chain_id,
+            pair[1].version
+        )));
+    }
+
+    #[test]
+    fn test_csv_and_json_export() {
+        let registry: ChainRegistry = [NamedChain::Mainnet, NamedChain::Base, NamedChain::Sepolia]
+            .into_iter()
+            .filter_map(ChainRecord::from_named)
+            .collect();
+        let matrix = RouteMatrix::from_registry(&registry);
+        assert_eq!(matrix.routes.len(), 4);
+
+        let csv = matrix.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next(),
+            Some("1,0,8453,6,mainnet,1,false,,1140,,false")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("1,0,8453,6,mainnet,2,true,20,1140,,true")
+        );
+
+        let json = matrix.to_json().unwrap();
+        let parsed: RouteMatrix = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, matrix);
+        assert!(json.contains(r#""source_domain": "ethereum""#));
+    }
+
+    #[test]
+    fn test_fee_from_registry_record() {
+        let mut registry: ChainRegistry = [NamedChain::Mainnet, NamedChain::Base]
+            .into_iter()
+            .filter_map(ChainRecord::from_named)
+            .collect();
+        let mut mainnet = registry.get(1).unwrap().clone();
+        mainnet.v2.as_mut().unwrap().fast_transfer_fee_bps = Some(3);
+        registry.insert(mainnet);
+        let matrix = RouteMatrix::from_registry(&registry);
+
+        assert_eq!(row(&matrix, 1, 8453, 2).fast_transfer_fee_bps, Some(3));
+        assert_eq!(row(&matrix, 1, 8453, 1).fast_transfer_fee_bps, None);
+        assert_eq!(row(&matrix, 8453, 1, 2).fast_transfer_fee_bps, None);
+    }
+
+    #[test]
+    fn test_fast_fee_from_iris_quotes() {
+        let fees: Vec<IrisFee> = serde_json::from_str(
+            r#"[{"finalityThreshold":1000,"minimumFee":1.3},{"finalityThreshold":2000,"minimumFee":0}]"#,
+        )
+        .unwrap();
+        assert_eq!(fast_fee_bps(&fees), Some(2));
+        assert_eq!(fast_fee_bps(&fees[1..]), None);
+    }
+
+    #[tokio::test]
+    async fn test_with_fees_queries_iris_per_route() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let iris_api =
+            url::Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let server = tokio::spawn(async move {
+            let mut paths = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![0; 4096];
+                let read = socket.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..read]).to_string();
+                paths.push(request.split_whitespace().nth(1).unwrap().to_string());
+                let body = r#"[{"finalityThreshold":1000,"minimumFee":1},{"finalityThreshold":2000,"minimumFee":0}]"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            paths
+        });
+
+        let environment = CctpEnvironment::Custom {
+            iris_api,
+            token_messenger: alloy_primitives::Address::repeat_byte(0x01),
+            message_transmitter: alloy_primitives::Address::repeat_byte(0x02),
+        };
+        let registry: ChainRegistry = [NamedChain::Mainnet, NamedChain::Base]
+            .into_iter()
+            .filter_map(ChainRecord::from_named)
+            .map(|record| ChainRecord {
+                environment: environment.clone(),
+                ..record
+            })
+            .collect();
+        let matrix = RouteMatrix::from_registry(&registry)
+            .with_fees(&Client::new())
+            .await
+            .unwrap();
+
+        assert_eq!(row(&matrix, 1, 8453, 2).fast_transfer_fee_bps, Some(1));
+        assert_eq!(row(&matrix, 8453, 1, 2).fast_transfer_fee_bps, Some(1));
+        assert_eq!(row(&matrix, 1, 8453, 1).fast_transfer_fee_bps, None);
+        assert_eq!(
+            server.await.unwrap(),
+            ["/v2/burn/USDC/fees/0/6", "/v2/burn/USDC/fees/6/0"]
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(
+            csv_field("http://localhost:8080/"),
+            "http://localhost:8080/"
+        );
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
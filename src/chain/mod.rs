@@ -11,7 +11,14 @@
 
 pub mod addresses;
 mod config;
+mod environment;
+mod matrix;
+mod registry;
 mod v2;
 
 pub use config::CctpV1;
-pub use v2::CctpV2;
+pub use environment::CctpEnvironment;
+pub use matrix::{RouteCapability, RouteMatrix};
+pub(crate) use registry::UNNAMED_CHAIN_IDS;
+pub use registry::{ChainRecord, ChainRegistry, Route, V1Deployment, V2Deployment};
+pub use v2::{CctpV2, CCTP_V2_MESSAGE_BODY_VERSION, CCTP_V2_MESSAGE_VERSION};
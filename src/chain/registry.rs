@@ -0,0 +1,963 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Runtime chain registry
+
+use std::collections::BTreeMap;
+
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use super::addresses::{CODEX_TESTNET_USDC_ADDRESS, CODEX_USDC_ADDRESS};
+use super::{CctpEnvironment, CctpV1, CctpV2};
+use crate::error::{CctpError, Result};
+use crate::protocol::DomainId;
+
+/// Codex mainnet chain id; Codex has no `NamedChain` variant
+const CODEX_CHAIN_ID: u64 = 81224;
+
+/// Codex testnet chain id
+const CODEX_TESTNET_CHAIN_ID: u64 = 812242;
+
+/// Built-in chains that `alloy_chains` does not name
+pub(crate) const UNNAMED_CHAIN_IDS: [u64; 2] = [CODEX_CHAIN_ID, CODEX_TESTNET_CHAIN_ID];
+
+/// CCTP configuration of one chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainRecord {
+    /// EVM chain id
+    pub chain_id: u64,
+    /// CCTP domain of the chain
+    pub domain: DomainId,
+    /// Environment the chain attests and mints through
+    #[serde(default)]
+    pub environment: CctpEnvironment,
+    /// Native USDC token address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usdc: Option<Address>,
+    /// CCTP v1 deployment, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub v1: Option<V1Deployment>,
+    /// CCTP v2 deployment, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub v2: Option<V2Deployment>,
+}
+
+/// CCTP v1 contracts and timings of a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct V1Deployment {
+    /// `TokenMessenger` contract address
+    pub token_messenger: Address,
+    /// `MessageTransmitter` contract address
+    pub message_transmitter: Address,
+    /// Average time to attestation, in seconds
+    pub confirmation_seconds: u64,
+}
+
+/// CCTP v2 contracts and timings of a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct V2Deployment {
+    /// `TokenMessengerV2` contract address
+    pub token_messenger: Address,
+    /// `MessageTransmitterV2` contract address
+    pub message_transmitter: Address,
+    /// Average Fast Transfer attestation time, in seconds
+    pub fast_transfer_seconds: u64,
+    /// Average Standard Transfer attestation time, in seconds
+    pub standard_transfer_seconds: u64,
+    /// Whether the chain offers Fast Transfer; defaults to `true`
+    #[serde(default = "default_fast_transfer")]
+    pub fast_transfer: bool,
+    /// Fast Transfer fee in basis points, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fast_transfer_fee_bps: Option<u32>,
+}
+
+fn default_fast_transfer() -> bool {
+    true
+}
+
+impl ChainRecord {
+    /// Build the record of a `NamedChain` from the built-in tables
+    ///
+    /// Returns `None` for chains without any CCTP deployment.
+    pub fn from_named(chain: NamedChain) -> Option<Self> {
+        let v1 = chain.is_supported().then(|| {
+            Some(V1Deployment {
+                token_messenger: chain.token_messenger_address().ok()?,
+                message_transmitter: chain.message_transmitter_address().ok()?,
+                confirmation_seconds: chain.confirmation_average_time_seconds().ok()?,
+            })
+        });
+        let v2 = chain.supports_cctp_v2().then(|| {
+            Some(V2Deployment {
+                token_messenger: chain.token_messenger_v2_address().ok()?,
+                message_transmitter: chain.message_transmitter_v2_address().ok()?,
+                fast_transfer_seconds: chain.fast_transfer_confirmation_time_seconds().ok()?,
+                standard_transfer_seconds: chain
+                    .standard_transfer_confirmation_time_seconds()
+                    .ok()?,
+                fast_transfer: chain.supports_fast_transfer().ok()?,
+                // The built-in fee is a placeholder; Circle publishes fees
+                // per pair through Iris, see `RouteMatrix::with_fees`
+                fast_transfer_fee_bps: None,
+            })
+        });
+        let (v1, v2) = (v1.flatten(), v2.flatten());
+        let domain = match (&v1, &v2) {
+            (_, Some(_)) => chain.cctp_v2_domain_id().ok()?,
+            (Some(_), None) => chain.cctp_domain_id().ok()?,
+            (None, None) => return None,
+        };
+
+        Some(Self {
+            chain_id: chain.into(),
+            domain,
+            environment: chain.environment(),
+            usdc: chain.usdc_address().ok(),
+            v1,
+            v2,
+        })
+    }
+
+    /// Build the record of `chain_id` from the built-in tables
+    ///
+    /// Unlike [`Self::from_named`], this also knows the chains that
+    /// `alloy_chains` does not name, such as Codex. Returns `None` for
+    /// chains without any CCTP deployment.
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        match chain_id {
+            CODEX_CHAIN_ID => Some(Self::op_stack_v2(
+                CODEX_CHAIN_ID,
+                DomainId::Codex,
+                CctpEnvironment::Mainnet,
+                CODEX_USDC_ADDRESS,
+            )),
+            CODEX_TESTNET_CHAIN_ID => Some(Self::op_stack_v2(
+                CODEX_TESTNET_CHAIN_ID,
+                DomainId::Codex,
+                CctpEnvironment::Testnet,
+                CODEX_TESTNET_USDC_ADDRESS,
+            )),
+            _ => Self::from_named(NamedChain::try_from(chain_id).ok()?),
+        }
+    }
+
+    /// Record of an OP Stack chain that is only known by chain id
+    fn op_stack_v2(
+        chain_id: u64,
+        domain: DomainId,
+        environment: CctpEnvironment,
+        usdc: Address,
+    ) -> Self {
+        Self {
+            chain_id,
+            domain,
+            usdc: Some(usdc),
+            v1: None,
+            v2: Some(V2Deployment {
+                token_messenger: environment.token_messenger_v2_address(),
+                message_transmitter: environment.message_transmitter_v2_address(),
+                fast_transfer_seconds: 8,
+                standard_transfer_seconds: 19 * 60,
+                fast_transfer: true,
+                fast_transfer_fee_bps: None,
+            }),
+            environment,
+        }
+    }
+
+    fn v1_deployment(&self) -> Result<&V1Deployment> {
+        self.v1
+            .as_ref()
+            .ok_or(CctpError::UnsupportedChainId(self.chain_id))
+    }
+
+    fn v2_deployment(&self) -> Result<&V2Deployment> {
+        self.v2
+            .as_ref()
+            .ok_or(CctpError::UnsupportedChainId(self.chain_id))
+    }
+}
+
+impl CctpV1 for ChainRecord {
+    fn confirmation_average_time_seconds(&self) -> Result<u64> {
+        Ok(self.v1_deployment()?.confirmation_seconds)
+    }
+
+    fn cctp_domain_id(&self) -> Result<DomainId> {
+        self.v1_deployment()?;
+        Ok(self.domain)
+    }
+
+    fn token_messenger_address(&self) -> Result<Address> {
+        Ok(self.v1_deployment()?.token_messenger)
+    }
+
+    fn message_transmitter_address(&self) -> Result<Address> {
+        Ok(self.v1_deployment()?.message_transmitter)
+    }
+
+    fn is_supported(&self) -> bool {
+        self.v1.is_some()
+    }
+}
+
+impl CctpV2 for ChainRecord {
+    fn supports_cctp_v2(&self) -> bool {
+        self.v2.is_some()
+    }
+
+    fn supports_fast_transfer(&self) -> Result<bool> {
+        Ok(self.v2_deployment()?.fast_transfer)
+    }
+
+    fn fast_transfer_fee_bps(&self) -> Result<Option<u32>> {
+        Ok(self.v2_deployment()?.fast_transfer_fee_bps)
+    }
+
+    fn token_messenger_v2_address(&self) -> Result<Address> {
+        Ok(self.v2_deployment()?.token_messenger)
+    }
+
+    fn message_transmitter_v2_address(&self) -> Result<Address> {
+        Ok(self.v2_deployment()?.message_transmitter)
+    }
+
+    fn cctp_v2_domain_id(&self) -> Result<DomainId> {
+        self.v2_deployment()?;
+        Ok(self.domain)
+    }
+
+    fn fast_transfer_confirmation_time_seconds(&self) -> Result<u64> {
+        Ok(self.v2_deployment()?.fast_transfer_seconds)
+    }
+
+    fn standard_transfer_confirmation_time_seconds(&self) -> Result<u64> {
+        Ok(self.v2_deployment()?.standard_transfer_seconds)
+    }
+
+    fn usdc_address(&self) -> Result<Address> {
+        self.usdc
+            .ok_or(CctpError::UnsupportedChainId(self.chain_id))
+    }
+
+    fn environment(&self) -> CctpEnvironment {
+        self.environment.clone()
+    }
+}
+
+/// Built-in configuration of a [`Chain`]
+///
+/// Named chains use the `NamedChain` tables, and other chain ids the
+/// built-in record from [`ChainRecord::from_chain_id`], if there is one.
+enum BuiltinChain {
+    Named(NamedChain),
+    Record(Box<ChainRecord>),
+    Unknown(u64),
+}
+
+impl BuiltinChain {
+    fn of(chain: Chain) -> Self {
+        match chain.named() {
+            Some(named) => Self::Named(named),
+            None => match ChainRecord::from_chain_id(chain.id()) {
+                Some(record) => Self::Record(Box::new(record)),
+                None => Self::Unknown(chain.id()),
+            },
+        }
+    }
+
+    fn v1(&self) -> Result<&dyn CctpV1> {
+        match self {
+            Self::Named(named) => Ok(named),
+            Self::Record(record) => Ok(&**record),
+            Self::Unknown(chain_id) => Err(CctpError::UnsupportedChainId(*chain_id)),
+        }
+    }
+
+    fn v2(&self) -> Result<&dyn CctpV2> {
+        match self {
+            Self::Named(named) => Ok(named),
+            Self::Record(record) => Ok(&**record),
+            Self::Unknown(chain_id) => Err(CctpError::UnsupportedChainId(*chain_id)),
+        }
+    }
+}
+
+impl CctpV1 for Chain {
+    fn confirmation_average_time_seconds(&self) -> Result<u64> {
+        BuiltinChain::of(*self)
+            .v1()?
+            .confirmation_average_time_seconds()
+    }
+
+    fn cctp_domain_id(&self) -> Result<DomainId> {
+        BuiltinChain::of(*self).v1()?.cctp_domain_id()
+    }
+
+    fn token_messenger_address(&self) -> Result<Address> {
+        BuiltinChain::of(*self).v1()?.token_messenger_address()
+    }
+
+    fn message_transmitter_address(&self) -> Result<Address> {
+        BuiltinChain::of(*self).v1()?.message_transmitter_address()
+    }
+
+    fn is_supported(&self) -> bool {
+        BuiltinChain::of(*self)
+            .v1()
+            .is_ok_and(|chain| chain.is_supported())
+    }
+}
+
+impl CctpV2 for Chain {
+    fn supports_cctp_v2(&self) -> bool {
+        BuiltinChain::of(*self)
+            .v2()
+            .is_ok_and(|chain| chain.supports_cctp_v2())
+    }
+
+    fn supports_fast_transfer(&self) -> Result<bool> {
+        BuiltinChain::of(*self).v2()?.supports_fast_transfer()
+    }
+
+    fn fast_transfer_fee_bps(&self) -> Result<Option<u32>> {
+        BuiltinChain::of(*self).v2()?.fast_transfer_fee_bps()
+    }
+
+    fn token_messenger_v2_address(&self) -> Result<Address> {
+        BuiltinChain::of(*self).v2()?.token_messenger_v2_address()
+    }
+
+    fn message_transmitter_v2_address(&self) -> Result<Address> {
+        BuiltinChain::of(*self)
+            .v2()?
+            .message_transmitter_v2_address()
+    }
+
+    fn cctp_v2_domain_id(&self) -> Result<DomainId> {
+        BuiltinChain::of(*self).v2()?.cctp_v2_domain_id()
+    }
+
+    fn fast_transfer_confirmation_time_seconds(&self) -> Result<u64> {
+        BuiltinChain::of(*self)
+            .v2()?
+            .fast_transfer_confirmation_time_seconds()
+    }
+
+    fn standard_transfer_confirmation_time_seconds(&self) -> Result<u64> {
+        BuiltinChain::of(*self)
+            .v2()?
+            .standard_transfer_confirmation_time_seconds()
+    }
+
+    fn usdc_address(&self) -> Result<Address> {
+        BuiltinChain::of(*self).v2()?.usdc_address()
+    }
+
+    fn environment(&self) -> CctpEnvironment {
+        BuiltinChain::of(*self)
+            .v2()
+            .map(|chain| chain.environment())
+            .unwrap_or_default()
+    }
+}
+
+/// Chain records keyed by chain id
+///
+/// The [`CctpV1`] and [`CctpV2`] implementations on `NamedChain` only know
+/// the chains compiled into this crate. A [`ChainRegistry`] holds the same
+/// configuration as plain [`ChainRecord`]s keyed by chain id, so forks,
+/// local devnets and newly launched chains can be described in a TOML or
+/// JSON file and merged over the built-in defaults.
+///
+/// Set a registry on [`CctpV2Bridge`](crate::CctpV2Bridge) or
+/// [`Cctp`](crate::Cctp) with `.registry(..)`, and the bridge resolves its
+/// chains through it before falling back to the built-in tables.
+///
+/// # File format
+///
+/// ```toml
+/// [[chains]]
+/// chain_id = 31337
+/// domain = "ethereum"
+/// environment = "testnet"
+/// usdc = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238"
+///
+/// [chains.v2]
+/// token_messenger = "0x8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA"
+/// message_transmitter = "0xE737e5cEBEEBa77EFE34D4aa090756590b1CE275"
+/// fast_transfer_seconds = 2
+/// standard_transfer_seconds = 2
+/// ```
+///
+/// A record loaded from a file replaces the built-in record with the same
+/// chain id as a whole.
+///
+/// # Example
+///
+/// ```rust
+/// use alloy_chains::NamedChain;
+/// use cctp_rs::{CctpV2, ChainRegistry};
+///
+/// let overrides = ChainRegistry::from_json(
+///     r#"{ "chains": [{
+///         "chain_id": 31337,
+///         "domain": "ethereum",
+///         "environment": "testnet",
+///         "v2": {
+///             "token_messenger": "0x8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA",
+///             "message_transmitter": "0xE737e5cEBEEBa77EFE34D4aa090756590b1CE275",
+///             "fast_transfer_seconds": 2,
+///             "standard_transfer_seconds": 2
+///         }
+///     }] }"#,
+/// )?;
+///
+/// let mut registry = ChainRegistry::builtin();
+/// registry.merge(overrides);
+///
+/// let anvil = NamedChain::AnvilHardhat;
+/// assert!(!anvil.supports_cctp_v2());
+/// assert!(registry.v2(&anvil).supports_cctp_v2());
+/// # Ok::<(), cctp_rs::CctpError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainRegistry {
+    #[serde(with = "records")]
+    chains: BTreeMap<u64, ChainRecord>,
+}
+
+impl ChainRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry holding every chain built into this crate
+    ///
+    /// Besides every `NamedChain` with a CCTP deployment, this includes
+    /// Codex and Codex testnet, which `alloy_chains` does not name.
+    pub fn builtin() -> Self {
+        NamedChain::iter()
+            .filter_map(ChainRecord::from_named)
+            .chain(
+                UNNAMED_CHAIN_IDS
+                    .into_iter()
+                    .filter_map(ChainRecord::from_chain_id),
+            )
+            .collect()
+    }
+
+    /// Parse a registry from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| CctpError::InvalidConfig(format!("invalid chain registry JSON: {e}")))
+    }
+
+    /// Parse a registry from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml)
+            .map_err(|e| CctpError::InvalidConfig(format!("invalid chain registry TOML: {e}")))
+    }
+
+    /// Add a record, returning the one it replaced
+    pub fn insert(&mut self, record: ChainRecord) -> Option<ChainRecord> {
+        self.chains.insert(record.chain_id, record)
+    }
+
+    /// Add every record of `other`, replacing records with the same chain id
+    pub fn merge(&mut self, other: ChainRegistry) {
+        self.chains.extend(other.chains);
+    }
+
+    /// Returns the record of `chain_id`, if registered
+    pub fn get(&self, chain_id: u64) -> Option<&ChainRecord> {
+        self.chains.get(&chain_id)
+    }
+
+    /// Iterate over all records in chain id order
+    pub fn records(&self) -> impl Iterator<Item = &ChainRecord> {
+        self.chains.values()
+    }
+
+    /// Resolve `chain` for CCTP v1, preferring its registered record
+    ///
+    /// `chain` is a `NamedChain` or a [`Chain`], which can also hold a
+    /// chain id without a `NamedChain` variant.
+    pub fn v1<'a, C>(&'a self, chain: &'a C) -> &'a dyn CctpV1
+    where
+        C: CctpV1 + Copy + Into<u64>,
+    {
+        match self.get((*chain).into()) {
+            Some(record) => record,
+            None => chain,
+        }
+    }
+
+    /// Resolve `chain` for CCTP v2, preferring its registered record
+    ///
+    /// `chain` is a `NamedChain` or a [`Chain`], which can also hold a
+    /// chain id without a `NamedChain` variant.
+    pub fn v2<'a, C>(&'a self, chain: &'a C) -> &'a dyn CctpV2
+    where
+        C: CctpV2 + Copy + Into<u64>,
+    {
+        match self.get((*chain).into()) {
+            Some(record) => record,
+            None => chain,
+        }
+    }
+
+    /// Environment of `chain`, preferring its registered record
+    pub fn environment<C>(&self, chain: C) -> CctpEnvironment
+    where
+        C: CctpV2 + Copy + Into<u64>,
+    {
+        self.v2(&chain).environment()
+    }
+
+    /// Returns the record of `domain` in `environment`
+    ///
+    /// The registry counterpart of [`DomainId::chain`], which also finds
+    /// registered chains and chains in a custom environment. Only records with a deployment count. If several
+    /// records match, the one with the lowest chain id is returned.
+    pub fn domain_record(
+        &self,
+        domain: DomainId,
+        environment: &CctpEnvironment,
+    ) -> Option<&ChainRecord> {
+        self.records().find(|record| {
+            record.domain == domain
+                && record.environment == *environment
+                && (record.v1.is_some() || record.v2.is_some())
+        })
+    }
+
+    /// Iterate over every route between two registered chains
+    ///
+    /// A route joins two records of different domains in the same
+    /// environment that share a CCTP version. Routes are ordered by source,
+    /// then destination chain id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alloy_chains::NamedChain;
+    /// use cctp_rs::ChainRegistry;
+    ///
+    /// let registry = ChainRegistry::builtin();
+    /// let route = registry
+    ///     .routes()
+    ///     .find(|route| {
+    ///         route.source_chain_id == u64::from(NamedChain::Mainnet)
+    ///             && route.destination_chain_id == u64::from(NamedChain::Linea)
+    ///     })
+    ///     .unwrap();
+    /// assert!(!route.v1);
+    /// assert!(route.v2 && route.fast_transfer && route.hooks);
+    /// ```
+    pub fn routes(&self) -> impl Iterator<Item = Route> + '_ {
+        self.records().flat_map(move |source| {
+            self.records()
+                .filter_map(move |destination| Route::between(source, destination))
+        })
+    }
+}
+
+/// A supported transfer direction between two chains, with its capabilities
+///
+/// Returned by [`ChainRegistry::routes`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Route {
+    /// Chain id of the chain USDC is burned on
+    pub source_chain_id: u64,
+    /// CCTP domain of the source chain
+    pub source_domain: DomainId,
+    /// Chain id of the chain USDC is minted on
+    pub destination_chain_id: u64,
+    /// CCTP domain of the destination chain
+    pub destination_domain: DomainId,
+    /// Environment both chains belong to
+    pub environment: CctpEnvironment,
+    /// Whether both chains have a CCTP v1 deployment
+    pub v1: bool,
+    /// Whether both chains have a CCTP v2 deployment
+    pub v2: bool,
+    /// Whether v2 Fast Transfer is available from the source chain
+    pub fast_transfer: bool,
+    /// Whether burns can carry hook data for the destination
+    pub hooks: bool,
+}
+
+impl Route {
+    fn between(source: &ChainRecord, destination: &ChainRecord) -> Option<Self> {
+        if source.domain == destination.domain || source.environment != destination.environment {
+            return None;
+        }
+        let v1 = source.v1.is_some() && destination.v1.is_some();
+        let v2 = source.v2.is_some() && destination.v2.is_some();
+        if !v1 && !v2 {
+            return None;
+        }
+
+        Some(Self {
+            source_chain_id: source.chain_id,
+            source_domain: source.domain,
+            destination_chain_id: destination.chain_id,
+            destination_domain: destination.domain,
+            environment: source.environment.clone(),
+            v1,
+            v2,
+            fast_transfer: v2 && source.supports_fast_transfer().unwrap_or(false),
+            // Hook data is a v2 message field
+            hooks: v2,
+        })
+    }
+}
+
+impl FromIterator<ChainRecord> for ChainRegistry {
+    fn from_iter<I: IntoIterator<Item = ChainRecord>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for record in iter {
+            registry.insert(record);
+        }
+        registry
+    }
+}
+
+/// Serializes the record map as a `chains` list
+mod records {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::ChainRecord;
+
+    pub(super) fn serialize<S: Serializer>(
+        chains: &BTreeMap<u64, ChainRecord>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(chains.values())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<u64, ChainRecord>, D::Error> {
+        let records = Vec::<ChainRecord>::deserialize(deserializer)?;
+        Ok(records
+            .into_iter()
+            .map(|record| (record.chain_id, record))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::addresses::{
+        CCTP_V2_MESSAGE_TRANSMITTER_TESTNET, CCTP_V2_TOKEN_MESSENGER_TESTNET,
+    };
+
+    fn devnet() -> ChainRecord {
+        ChainRecord {
+            chain_id: NamedChain::AnvilHardhat.into(),
+            domain: DomainId::Ethereum,
+            environment: CctpEnvironment::Testnet,
+            usdc: Some(Address::repeat_byte(0x11)),
+            v1: None,
+            v2: Some(V2Deployment {
+                token_messenger: CCTP_V2_TOKEN_MESSENGER_TESTNET,
+                message_transmitter: CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
+                fast_transfer_seconds: 2,
+                standard_transfer_seconds: 4,
+                fast_transfer: false,
+                fast_transfer_fee_bps: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_builtin_matches_named_chain_tables() {
+        let registry = ChainRegistry::builtin();
+
+        for chain in NamedChain::iter().filter(|chain| chain.supports_cctp_v2()) {
+            let record = registry.get(chain.into()).unwrap();
+            assert_eq!(record.supports_cctp_v2(), chain.supports_cctp_v2());
+            assert_eq!(
+                record.token_messenger_v2_address().ok(),
+                chain.token_messenger_v2_address().ok()
+            );
+            assert_eq!(
+                record.token_messenger_address().ok(),
+                chain.token_messenger_address().ok()
+            );
+            assert_eq!(record.environment, chain.environment());
+            assert_eq!(record.usdc, chain.usdc_address().ok());
+        }
+        // No v1 contracts are listed for Fuji, so only v2 is recorded
+        let fuji = registry.get(NamedChain::AvalancheFuji.into()).unwrap();
+        assert!(fuji.v1.is_none());
+        assert_eq!(fuji.domain, DomainId::Avalanche);
+    }
+
+    #[test]
+    fn test_every_evm_domain_has_mainnet_and_testnet_chains() {
+        let registry = ChainRegistry::builtin();
+        // No wildcard arm: a new DomainId variant does not compile until it
+        // is chained in here, so the loop below always sees every variant
+        let next = |domain| match domain {
+            DomainId::Ethereum => Some(DomainId::Avalanche),
+            DomainId::Avalanche => Some(DomainId::Optimism),
+            DomainId::Optimism => Some(DomainId::Arbitrum),
+            DomainId::Arbitrum => Some(DomainId::Solana),
+            DomainId::Solana => Some(DomainId::Base),
+            DomainId::Base => Some(DomainId::Polygon),
+            DomainId::Polygon => Some(DomainId::Unichain),
+            DomainId::Unichain => Some(DomainId::Linea),
+            DomainId::Linea => Some(DomainId::Codex),
+            DomainId::Codex => Some(DomainId::Sonic),
+            DomainId::Sonic => Some(DomainId::WorldChain),
+            DomainId::WorldChain => Some(DomainId::Monad),
+            DomainId::Monad => Some(DomainId::Sei),
+            DomainId::Sei => Some(DomainId::BnbSmartChain),
+            DomainId::BnbSmartChain => Some(DomainId::Xdc),
+            DomainId::Xdc => Some(DomainId::HyperEvm),
+            DomainId::HyperEvm => Some(DomainId::Ink),
+            DomainId::Ink => Some(DomainId::Plume),
+            DomainId::Plume => Some(DomainId::StarknetTestnet),
+            DomainId::StarknetTestnet => Some(DomainId::ArcTestnet),
+            DomainId::ArcTestnet => None,
+        };
+        let domains: Vec<_> =
+            std::iter::successors(Some(DomainId::Ethereum), |&d| next(d)).collect();
+
+        for domain in domains.into_iter().filter(|domain| domain.is_evm()) {
+            let records: Vec<_> = registry
+                .records()
+                .filter(|record| record.domain == domain && record.supports_cctp_v2())
+                .collect();
+            assert!(
+                records
+                    .iter()
+                    .any(|record| record.environment == CctpEnvironment::Testnet),
+                "{domain} has no testnet chain"
+            );
+            // Arc has no mainnet yet
+            if domain != DomainId::ArcTestnet {
+                assert!(
+                    records
+                        .iter()
+                        .any(|record| record.environment == CctpEnvironment::Mainnet),
+                    "{domain} has no mainnet chain"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolves_registered_chain_before_builtin_tables() {
+        let mut registry = ChainRegistry::builtin();
+        let anvil = NamedChain::AnvilHardhat;
+        assert!(!registry.v2(&anvil).supports_cctp_v2());
+
+        registry.insert(devnet());
+        let resolved = registry.v2(&anvil);
+        assert_eq!(resolved.cctp_v2_domain_id().unwrap(), DomainId::Ethereum);
+        assert_eq!(
+            resolved.fast_transfer_confirmation_time_seconds().unwrap(),
+            2
+        );
+        assert_eq!(registry.environment(anvil), CctpEnvironment::Testnet);
+        assert!(matches!(
+            registry.v1(&anvil).token_messenger_address(),
+            Err(CctpError::UnsupportedChainId(31337))
+        ));
+
+        // Unregistered chains fall through to the built-in tables
+        let empty = ChainRegistry::new();
+        assert_eq!(
+            empty.v2(&NamedChain::Linea).cctp_v2_domain_id().unwrap(),
+            DomainId::Linea
+        );
+    }
+
+    #[test]
+    fn test_resolves_chains_without_named_variant() {
+        let codex = Chain::from_id(CODEX_CHAIN_ID);
+        assert!(codex.is_id());
+        assert!(codex.supports_cctp_v2());
+        assert!(!codex.is_supported());
+        assert_eq!(codex.cctp_v2_domain_id().unwrap(), DomainId::Codex);
+        assert_eq!(codex.usdc_address().unwrap(), CODEX_USDC_ADDRESS);
+        assert_eq!(
+            Chain::from_id(CODEX_TESTNET_CHAIN_ID).environment(),
+            CctpEnvironment::Testnet
+        );
+
+        // Named chains keep their `NamedChain` errors
+        assert!(matches!(
+            Chain::from(NamedChain::Moonbeam).token_messenger_v2_address(),
+            Err(CctpError::UnsupportedChain(NamedChain::Moonbeam))
+        ));
+        let unknown = Chain::from_id(900_001);
+        assert!(!unknown.supports_cctp_v2());
+        assert!(matches!(
+            unknown.cctp_v2_domain_id(),
+            Err(CctpError::UnsupportedChainId(900_001))
+        ));
+
+        let mut registry = ChainRegistry::new();
+        registry.insert(ChainRecord {
+            chain_id: 900_001,
+            ..devnet()
+        });
+        assert_eq!(
+            registry.v2(&unknown).cctp_v2_domain_id().unwrap(),
+            DomainId::Ethereum
+        );
+        assert_eq!(registry.environment(unknown), CctpEnvironment::Testnet);
+    }
+
+    #[test]
+    fn test_fast_transfer_flag() {
+        let devnet = devnet();
+        assert!(!devnet.supports_fast_transfer().unwrap());
+        let registry: ChainRegistry = [
+            devnet,
+            ChainRecord::from_named(NamedChain::BaseSepolia).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        let fast: Vec<_> = registry
+            .routes()
+            .map(|route| (route.source_chain_id, route.fast_transfer))
+            .collect();
+        assert_eq!(
+            fast,
+            [(31337, false), (u64::from(NamedChain::BaseSepolia), true)]
+        );
+
+        // Records written before the flag existed keep Fast Transfer
+        let deployment: V2Deployment = serde_json::from_str(
+            r#"{
+                "token_messenger": "0x8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA",
+                "message_transmitter": "0xE737e5cEBEEBa77EFE34D4aa090756590b1CE275",
+                "fast_transfer_seconds": 2,
+                "standard_transfer_seconds": 2
+            }"#,
+        )
+        .unwrap();
+        assert!(deployment.fast_transfer);
+    }
+
+    #[test]
+    fn test_domain_record_finds_custom_chains() {
+        let mut registry = ChainRegistry::builtin();
+        assert_eq!(
+            registry
+                .domain_record(DomainId::Codex, &CctpEnvironment::Mainnet)
+                .map(|record| record.chain_id),
+            Some(CODEX_CHAIN_ID)
+        );
+
+        let staging = CctpEnvironment::Custom {
+            iris_api: "http://localhost:8080".parse().unwrap(),
+            token_messenger: CCTP_V2_TOKEN_MESSENGER_TESTNET,
+            message_transmitter: CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
+        };
+        assert!(registry
+            .domain_record(DomainId::Ethereum, &staging)
+            .is_none());
+        registry.insert(ChainRecord {
+            environment: staging.clone(),
+            ..devnet()
+        });
+        assert_eq!(
+            registry
+                .domain_record(DomainId::Ethereum, &staging)
+                .map(|record| record.chain_id),
+            Some(31337)
+        );
+    }
+
+    #[test]
+    fn test_routes_stay_within_environment() {
+        let registry = ChainRegistry::builtin();
+        let routes: Vec<_> = registry.routes().collect();
+
+        for route in &routes {
+            assert_ne!(route.source_domain, route.destination_domain);
+            let source = registry.get(route.source_chain_id).unwrap();
+            let destination = registry.get(route.destination_chain_id).unwrap();
+            assert_eq!(source.environment, destination.environment);
+            assert_eq!(route.environment, source.environment);
+        }
+
+        let find = |source: NamedChain, destination: NamedChain| {
+            routes.iter().find(|route| {
+                route.source_chain_id == u64::from(source)
+                    && route.destination_chain_id == u64::from(destination)
+            })
+        };
+        let legacy = find(NamedChain::Mainnet, NamedChain::Arbitrum).unwrap();
+        assert!(legacy.v1 && legacy.v2);
+        assert!(find(NamedChain::Arbitrum, NamedChain::Mainnet).is_some());
+        assert!(find(NamedChain::Mainnet, NamedChain::BaseSepolia).is_none());
+        // Fuji has no v1 contracts, so only v2 connects it
+        let fuji = find(NamedChain::AvalancheFuji, NamedChain::Sepolia).unwrap();
+        assert!(!fuji.v1 && fuji.v2);
+    }
+
+    #[test]
+    fn test_merge_replaces_whole_records() {
+        let mut registry = ChainRegistry::builtin();
+        let overrides =
+            ChainRegistry::from_json(r#"{ "chains": [{ "chain_id": 1, "domain": "ethereum" }] }"#)
+                .unwrap();
+
+        registry.merge(overrides);
+        let mainnet = registry.get(1).unwrap();
+        assert!(!mainnet.supports_cctp_v2());
+        assert_eq!(mainnet.environment, CctpEnvironment::Mainnet);
+        assert!(registry.get(NamedChain::Base.into()).is_some());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let registry: ChainRegistry = [devnet()].into_iter().collect();
+        let json = serde_json::to_string(&registry).unwrap();
+        assert!(json.starts_with(r#"{"chains":[{"chain_id":31337"#));
+        assert_eq!(ChainRegistry::from_json(&json).unwrap(), registry);
+
+        let err = ChainRegistry::from_json(r#"{ "chains": [{ "chain_id": 1 }] }"#).unwrap_err();
+        assert!(matches!(err, CctpError::InvalidConfig(_)));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let registry = ChainRegistry::from_toml(
+            r#"
+            [[chains]]
+            chain_id = 31337
+            domain = "ethereum"
+            environment = "testnet"
+            usdc = "0x1111111111111111111111111111111111111111"
+
+            [chains.v2]
+            token_messenger = "0x8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA"
+            message_transmitter = "0xE737e5cEBEEBa77EFE34D4aa090756590b1CE275"
+            fast_transfer_seconds = 2
+            standard_transfer_seconds = 4
+            fast_transfer = false
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(registry.get(31337), Some(&devnet()));
+    }
+}
@@ -10,12 +10,16 @@
 use alloy_chains::NamedChain;
 use alloy_primitives::Address;
 
-use super::addresses::{
-    CCTP_V2_MESSAGE_TRANSMITTER_MAINNET, CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
-    CCTP_V2_TOKEN_MESSENGER_MAINNET, CCTP_V2_TOKEN_MESSENGER_TESTNET,
-};
+use super::addresses::*;
+use super::CctpEnvironment;
 use crate::{CctpError, DomainId, Result};
 
+/// Message format version of every `MessageTransmitterV2` deployment
+pub const CCTP_V2_MESSAGE_VERSION: u32 = 1;
+
+/// Burn message body version of every `TokenMessengerV2` deployment
+pub const CCTP_V2_MESSAGE_BODY_VERSION: u32 = 1;
+
 /// CCTP v2 chain configuration trait
 ///
 /// Implemented on `alloy_chains::NamedChain` to provide v2-specific
@@ -41,7 +45,7 @@ use crate::{CctpError, DomainId, Result};
 pub trait CctpV2 {
     /// Returns true if this chain supports CCTP v2
     ///
-    /// All v1 chains support v2, plus 19 additional v2-only chains.
+    /// All v1 chains support v2, plus the v2-only chains and their testnets.
     fn supports_cctp_v2(&self) -> bool;
 
     /// Returns true if this chain supports Fast Transfer
@@ -103,6 +107,22 @@ pub trait CctpV2 {
     ///
     /// See: <https://developers.circle.com/stablecoins/required-block-confirmations>
     fn standard_transfer_confirmation_time_seconds(&self) -> Result<u64>;
+
+    /// Returns the native USDC token address on this chain
+    ///
+    /// This is the Circle-issued token that CCTP burns and mints, not a
+    /// bridged variant. Returns an error for chains without native USDC,
+    /// such as BNB Smart Chain, whose CCTP domain only moves USYC.
+    ///
+    /// See: <https://developers.circle.com/stablecoins/usdc-contract-addresses>
+    fn usdc_address(&self) -> Result<Address>;
+
+    /// Returns the environment this chain attests and mints through
+    ///
+    /// Decides the Iris API a bridge polls. Chains outside Circle's two
+    /// environments are described by a [`ChainRecord`](super::ChainRecord)
+    /// with a [`CctpEnvironment::Custom`] environment.
+    fn environment(&self) -> CctpEnvironment;
 }
 
 impl CctpV2 for NamedChain {
@@ -123,11 +143,30 @@ impl CctpV2 for NamedChain {
                 | Self::Polygon
                 | Self::PolygonAmoy
                 | Self::Unichain
-                // v2-only priority chains
-                // (BNB Smart Chain / domain 17 omitted: USYC-only on this domain)
+                | Self::UnichainSepolia
+                // v2-only chains
                 | Self::Linea
+                | Self::LineaSepolia
                 | Self::Sonic
+                | Self::SonicTestnet
+                | Self::World
+                | Self::WorldSepolia
+                | Self::Monad
+                | Self::MonadTestnet
                 | Self::Sei
+                | Self::SeiTestnet
+                // Domain 17 moves USYC only; there is no native USDC to burn
+                | Self::BinanceSmartChain
+                | Self::BinanceSmartChainTestnet
+                | Self::XdcMainnet
+                | Self::XdcTestnet
+                | Self::Hyperliquid
+                | Self::HyperliquidTestnet
+                | Self::Ink
+                | Self::InkSepolia
+                | Self::Plume
+                | Self::PlumeTestnet
+                | Self::ArcTestnet
         )
     }
 
@@ -157,11 +196,7 @@ impl CctpV2 for NamedChain {
         }
 
         // V2 uses unified addresses across all chains within each environment
-        Ok(if self.is_testnet() {
-            CCTP_V2_TOKEN_MESSENGER_TESTNET
-        } else {
-            CCTP_V2_TOKEN_MESSENGER_MAINNET
-        })
+        Ok(self.environment().token_messenger_v2_address())
     }
 
     fn message_transmitter_v2_address(&self) -> Result<Address> {
@@ -170,11 +205,7 @@ impl CctpV2 for NamedChain {
         }
 
         // V2 uses unified addresses across all chains within each environment
-        Ok(if self.is_testnet() {
-            CCTP_V2_MESSAGE_TRANSMITTER_TESTNET
-        } else {
-            CCTP_V2_MESSAGE_TRANSMITTER_MAINNET
-        })
+        Ok(self.environment().message_transmitter_v2_address())
     }
 
     fn cctp_v2_domain_id(&self) -> Result<DomainId> {
@@ -190,11 +221,19 @@ impl CctpV2 for NamedChain {
             Self::Arbitrum | Self::ArbitrumSepolia => DomainId::Arbitrum,
             Self::Base | Self::BaseSepolia => DomainId::Base,
             Self::Polygon | Self::PolygonAmoy => DomainId::Polygon,
-            Self::Unichain => DomainId::Unichain,
-            // v2-only priority chains
-            Self::Linea => DomainId::Linea,
-            Self::Sonic => DomainId::Sonic,
-            Self::Sei => DomainId::Sei,
+            Self::Unichain | Self::UnichainSepolia => DomainId::Unichain,
+            // v2-only chains
+            Self::Linea | Self::LineaSepolia => DomainId::Linea,
+            Self::Sonic | Self::SonicTestnet => DomainId::Sonic,
+            Self::World | Self::WorldSepolia => DomainId::WorldChain,
+            Self::Monad | Self::MonadTestnet => DomainId::Monad,
+            Self::Sei | Self::SeiTestnet => DomainId::Sei,
+            Self::BinanceSmartChain | Self::BinanceSmartChainTestnet => DomainId::BnbSmartChain,
+            Self::XdcMainnet | Self::XdcTestnet => DomainId::Xdc,
+            Self::Hyperliquid | Self::HyperliquidTestnet => DomainId::HyperEvm,
+            Self::Ink | Self::InkSepolia => DomainId::Ink,
+            Self::Plume | Self::PlumeTestnet => DomainId::Plume,
+            Self::ArcTestnet => DomainId::ArcTestnet,
             // This is unreachable due to supports_cctp_v2() check above
             _ => return Err(CctpError::UnsupportedChain(*self)),
         })
@@ -221,13 +260,24 @@ impl CctpV2 for NamedChain {
             // Polygon: ~8 seconds (1 block confirmation)
             Self::Polygon | Self::PolygonAmoy => 8,
             // Unichain: ~8 seconds (1 block confirmation)
-            Self::Unichain => 8,
+            Self::Unichain | Self::UnichainSepolia => 8,
             // Linea: ~8 seconds (vs 6-32 hours for Standard!)
-            Self::Linea => 8,
+            Self::Linea | Self::LineaSepolia => 8,
+            // OP Stack and Arbitrum Orbit L2s: ~8 seconds (1 block confirmation)
+            Self::World | Self::WorldSepolia => 8,
+            Self::Ink | Self::InkSepolia => 8,
+            Self::Plume | Self::PlumeTestnet => 8,
+            // XDC: ~8 seconds (1 block confirmation)
+            Self::XdcMainnet | Self::XdcTestnet => 8,
             // Sonic: ~5 seconds (high-performance chain)
-            Self::Sonic => 5,
+            Self::Sonic | Self::SonicTestnet => 5,
             // Sei: ~5 seconds (parallel EVM)
-            Self::Sei => 5,
+            Self::Sei | Self::SeiTestnet => 5,
+            // Monad, BNB Smart Chain, HyperEVM, Arc: ~5 seconds (fast native finality)
+            Self::Monad | Self::MonadTestnet => 5,
+            Self::BinanceSmartChain | Self::BinanceSmartChainTestnet => 5,
+            Self::Hyperliquid | Self::HyperliquidTestnet => 5,
+            Self::ArcTestnet => 5,
             _ => return Err(CctpError::UnsupportedChain(*self)),
         })
     }
@@ -245,20 +295,80 @@ impl CctpV2 for NamedChain {
             Self::Arbitrum | Self::ArbitrumSepolia => 19 * 60,
             Self::Base | Self::BaseSepolia => 19 * 60,
             Self::Optimism | Self::OptimismSepolia => 19 * 60,
-            Self::Unichain => 19 * 60,
+            Self::Unichain | Self::UnichainSepolia => 19 * 60,
+            Self::World | Self::WorldSepolia => 19 * 60,
+            Self::Ink | Self::InkSepolia => 19 * 60,
+            Self::Plume | Self::PlumeTestnet => 19 * 60,
             // Avalanche: ~20 seconds (native finality)
             Self::Avalanche | Self::AvalancheFuji => 20,
             // Polygon: ~8 minutes (PoS finality)
             Self::Polygon | Self::PolygonAmoy => 8 * 60,
             // Linea: 6-32 hours (zkEVM proof generation) - use conservative 8 hours
-            Self::Linea => 8 * 60 * 60,
+            Self::Linea | Self::LineaSepolia => 8 * 60 * 60,
+            // XDC: ~10 seconds (XDPoS finality)
+            Self::XdcMainnet | Self::XdcTestnet => 10,
             // Sonic: ~5 seconds (high-performance chain, native finality)
-            Self::Sonic => 5,
+            Self::Sonic | Self::SonicTestnet => 5,
             // Sei: ~5 seconds (parallel EVM, native finality)
-            Self::Sei => 5,
+            Self::Sei | Self::SeiTestnet => 5,
+            // Monad, BNB Smart Chain, HyperEVM, Arc: ~5 seconds (native finality)
+            Self::Monad | Self::MonadTestnet => 5,
+            Self::BinanceSmartChain | Self::BinanceSmartChainTestnet => 5,
+            Self::Hyperliquid | Self::HyperliquidTestnet => 5,
+            Self::ArcTestnet => 5,
+            _ => return Err(CctpError::UnsupportedChain(*self)),
+        })
+    }
+
+    fn usdc_address(&self) -> Result<Address> {
+        Ok(match self {
+            // Mainnets
+            Self::Mainnet => ETHEREUM_USDC_ADDRESS,
+            Self::Avalanche => AVALANCHE_USDC_ADDRESS,
+            Self::Optimism => OPTIMISM_USDC_ADDRESS,
+            Self::Arbitrum => ARBITRUM_USDC_ADDRESS,
+            Self::Base => BASE_USDC_ADDRESS,
+            Self::Polygon => POLYGON_USDC_ADDRESS,
+            Self::Unichain => UNICHAIN_USDC_ADDRESS,
+            Self::Linea => LINEA_USDC_ADDRESS,
+            Self::Sonic => SONIC_USDC_ADDRESS,
+            Self::World => WORLD_CHAIN_USDC_ADDRESS,
+            Self::Monad => MONAD_USDC_ADDRESS,
+            Self::Sei => SEI_USDC_ADDRESS,
+            Self::XdcMainnet => XDC_USDC_ADDRESS,
+            Self::Hyperliquid => HYPEREVM_USDC_ADDRESS,
+            Self::Ink => INK_USDC_ADDRESS,
+            Self::Plume => PLUME_USDC_ADDRESS,
+            // Testnets
+            Self::Sepolia => ETHEREUM_SEPOLIA_USDC_ADDRESS,
+            Self::AvalancheFuji => AVALANCHE_FUJI_USDC_ADDRESS,
+            Self::OptimismSepolia => OPTIMISM_SEPOLIA_USDC_ADDRESS,
+            Self::ArbitrumSepolia => ARBITRUM_SEPOLIA_USDC_ADDRESS,
+            Self::BaseSepolia => BASE_SEPOLIA_USDC_ADDRESS,
+            Self::PolygonAmoy => POLYGON_AMOY_USDC_ADDRESS,
+            Self::UnichainSepolia => UNICHAIN_SEPOLIA_USDC_ADDRESS,
+            Self::LineaSepolia => LINEA_SEPOLIA_USDC_ADDRESS,
+            Self::SonicTestnet => SONIC_TESTNET_USDC_ADDRESS,
+            Self::WorldSepolia => WORLD_CHAIN_SEPOLIA_USDC_ADDRESS,
+            Self::MonadTestnet => MONAD_TESTNET_USDC_ADDRESS,
+            Self::SeiTestnet => SEI_TESTNET_USDC_ADDRESS,
+            Self::XdcTestnet => XDC_APOTHEM_USDC_ADDRESS,
+            Self::HyperliquidTestnet => HYPEREVM_TESTNET_USDC_ADDRESS,
+            Self::InkSepolia => INK_SEPOLIA_USDC_ADDRESS,
+            Self::PlumeTestnet => PLUME_TESTNET_USDC_ADDRESS,
+            Self::ArcTestnet => ARC_TESTNET_USDC_ADDRESS,
+            // BNB Smart Chain has no native USDC on CCTP
             _ => return Err(CctpError::UnsupportedChain(*self)),
         })
     }
+
+    fn environment(&self) -> CctpEnvironment {
+        if self.is_testnet() {
+            CctpEnvironment::Testnet
+        } else {
+            CctpEnvironment::Mainnet
+        }
+    }
 }
 
 #[cfg(test)]
@@ -274,12 +384,63 @@ mod tests {
     #[case(NamedChain::Linea, true)]
     #[case(NamedChain::Sonic, true)]
     #[case(NamedChain::Sei, true)]
-    #[case(NamedChain::BinanceSmartChain, false)]
+    #[case(NamedChain::World, true)]
+    #[case(NamedChain::Hyperliquid, true)]
+    #[case(NamedChain::InkSepolia, true)]
+    #[case(NamedChain::BinanceSmartChain, true)]
     #[case(NamedChain::Moonbeam, false)]
+    #[case(NamedChain::Gnosis, false)]
     fn test_v2_chain_support(#[case] chain: NamedChain, #[case] expected: bool) {
         assert_eq!(chain.supports_cctp_v2(), expected);
     }
 
+    #[test]
+    fn test_every_v2_chain_has_complete_tables() {
+        for chain in NamedChain::iter().filter(|chain| chain.supports_cctp_v2()) {
+            let domain = chain.cctp_v2_domain_id().unwrap();
+            assert!(domain.is_evm(), "{chain} maps to non-EVM {domain}");
+            assert!(chain.fast_transfer_confirmation_time_seconds().is_ok());
+            assert!(chain.standard_transfer_confirmation_time_seconds().is_ok());
+            assert!(chain.fast_transfer_fee_bps().is_ok());
+        }
+    }
+
+    #[rstest]
+    #[case(NamedChain::Mainnet, ETHEREUM_USDC_ADDRESS)]
+    #[case(NamedChain::Base, BASE_USDC_ADDRESS)]
+    #[case(NamedChain::Hyperliquid, HYPEREVM_USDC_ADDRESS)]
+    #[case(NamedChain::Sepolia, ETHEREUM_SEPOLIA_USDC_ADDRESS)]
+    #[case(NamedChain::ArcTestnet, ARC_TESTNET_USDC_ADDRESS)]
+    fn test_usdc_address(#[case] chain: NamedChain, #[case] expected: Address) {
+        assert_eq!(chain.usdc_address().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_usdc_address_per_environment() {
+        let chains: Vec<_> = NamedChain::iter()
+            .filter(|chain| chain.supports_cctp_v2())
+            .collect();
+        for chain in &chains {
+            if *chain == NamedChain::BinanceSmartChain
+                || *chain == NamedChain::BinanceSmartChainTestnet
+            {
+                assert!(chain.usdc_address().is_err());
+            } else {
+                assert!(chain.usdc_address().is_ok(), "{chain} has no USDC address");
+            }
+        }
+
+        // No USDC address is shared between two chains
+        let mut addresses: Vec<_> = chains
+            .iter()
+            .filter_map(|c| c.usdc_address().ok())
+            .collect();
+        let count = addresses.len();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), count);
+    }
+
     #[test]
     fn test_fast_transfer_support() {
         // All v2 chains support fast transfer
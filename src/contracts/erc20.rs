@@ -4,15 +4,16 @@
 //! ERC20 contract bindings for approval and allowance operations
 //!
 //! This module provides utilities for checking and setting ERC20 token allowances,
-//! which are required before calling CCTP burn operations.
+//! which are required before calling CCTP burn operations, including USDC's
+//! signature-based EIP-2612 permits and EIP-3009 authorizations.
 
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, U256};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, Signature, B256, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::sol;
 use tracing::{debug, info};
 
+use crate::protocol::{signature_vrs, Permit, ReceiveWithAuthorization};
 use Erc20::Erc20Instance;
 
 /// ERC20 contract wrapper for approval operations
@@ -46,11 +47,11 @@ use Erc20::Erc20Instance;
 /// # Ok(())
 /// # }
 /// ```
-pub struct Erc20Contract<P: Provider<Ethereum>> {
-    instance: Erc20Instance<P>,
+pub struct Erc20Contract<P: Provider<N>, N: Network = Ethereum> {
+    instance: Erc20Instance<P, N>,
 }
 
-impl<P: Provider<Ethereum>> Erc20Contract<P> {
+impl<P: Provider<N>, N: Network> Erc20Contract<P, N> {
     /// Create a new ERC20 contract wrapper
     pub fn new(address: Address, provider: P) -> Self {
         debug!(
@@ -118,7 +119,7 @@ impl<P: Provider<Ethereum>> Erc20Contract<P> {
         from: Address,
         spender: Address,
         amount: U256,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             from = %from,
             spender = %spender,
@@ -161,19 +162,149 @@ impl<P: Provider<Ethereum>> Erc20Contract<P> {
         Ok(result)
     }
 
+    /// Token name, as used in the EIP-712 domain
+    pub async fn name(&self) -> Result<String, alloy_contract::Error> {
+        self.instance.name().call().await
+    }
+
+    /// EIP-712 domain version (`"2"` for USDC)
+    pub async fn version(&self) -> Result<String, alloy_contract::Error> {
+        self.instance.version().call().await
+    }
+
+    /// The token's EIP-712 domain separator
+    pub async fn domain_separator(&self) -> Result<B256, alloy_contract::Error> {
+        self.instance.DOMAIN_SEPARATOR().call().await
+    }
+
+    /// Next EIP-2612 permit nonce for `owner`
+    pub async fn nonces(&self, owner: Address) -> Result<U256, alloy_contract::Error> {
+        let nonce = self.instance.nonces(owner).call().await?;
+
+        debug!(
+            owner = %owner,
+            nonce = %nonce,
+            contract_address = %self.instance.address(),
+            event = "permit_nonce_retrieved"
+        );
+
+        Ok(nonce)
+    }
+
+    /// Whether an EIP-3009 authorization nonce was already used or cancelled
+    ///
+    /// EIP-3009 nonces are random 32-byte values chosen by the signer, not a
+    /// counter, so this is the only way to check one before submitting.
+    pub async fn authorization_state(
+        &self,
+        authorizer: Address,
+        nonce: B256,
+    ) -> Result<bool, alloy_contract::Error> {
+        self.instance
+            .authorizationState(authorizer, nonce)
+            .call()
+            .await
+    }
+
+    /// Create a transaction request that submits a signed EIP-2612 permit
+    ///
+    /// Any account can submit a permit; `from` only pays the gas.
+    ///
+    /// # Arguments
+    ///
+    /// * `permit` - The permit that was signed
+    /// * `signature` - The owner's signature over the permit's EIP-712 digest
+    /// * `from` - The address that will send the transaction
+    pub fn permit_transaction(
+        &self,
+        permit: &Permit,
+        signature: &Signature,
+        from: Address,
+    ) -> N::TransactionRequest {
+        info!(
+            owner = %permit.owner,
+            spender = %permit.spender,
+            value = %permit.value,
+            deadline = %permit.deadline,
+            from = %from,
+            contract_address = %self.instance.address(),
+            event = "permit_transaction_created"
+        );
+
+        let (v, r, s) = signature_vrs(signature);
+        self.instance
+            .permit(
+                permit.owner,
+                permit.spender,
+                permit.value,
+                permit.deadline,
+                v,
+                r,
+                s,
+            )
+            .from(from)
+            .into_transaction_request()
+    }
+
+    /// Create a transaction request that submits a signed EIP-3009
+    /// `receiveWithAuthorization`
+    ///
+    /// The token only accepts this call from `authorization.to`, so `from`
+    /// must be the payee (typically a helper contract's caller path).
+    pub fn receive_with_authorization_transaction(
+        &self,
+        authorization: &ReceiveWithAuthorization,
+        signature: &Signature,
+        from: Address,
+    ) -> N::TransactionRequest {
+        info!(
+            authorizer = %authorization.from,
+            to = %authorization.to,
+            value = %authorization.value,
+            from = %from,
+            contract_address = %self.instance.address(),
+            event = "receive_with_authorization_transaction_created"
+        );
+
+        let (v, r, s) = signature_vrs(signature);
+        self.instance
+            .receiveWithAuthorization(
+                authorization.from,
+                authorization.to,
+                authorization.value,
+                authorization.validAfter,
+                authorization.validBefore,
+                authorization.nonce,
+                v,
+                r,
+                s,
+            )
+            .from(from)
+            .into_transaction_request()
+    }
+
     /// Returns the contract address
     pub fn address(&self) -> Address {
         *self.instance.address()
     }
 }
 
-// Minimal ERC20 interface for approval operations
+// Minimal ERC20 interface for approval operations, plus the EIP-2612 and
+// EIP-3009 extensions implemented by USDC
 sol!(
     #[allow(missing_docs)]
+    #[allow(clippy::too_many_arguments)]
     #[sol(rpc)]
     contract Erc20 {
         function allowance(address owner, address spender) external view returns (uint256);
         function approve(address spender, uint256 amount) external returns (bool);
         function balanceOf(address account) external view returns (uint256);
+        function name() external view returns (string);
+        function version() external view returns (string);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+        function nonces(address owner) external view returns (uint256);
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        function authorizationState(address authorizer, bytes32 nonce) external view returns (bool);
+        function receiveWithAuthorization(address from, address to, uint256 value, uint256 validAfter, uint256 validBefore, bytes32 nonce, uint8 v, bytes32 r, bytes32 s) external;
     }
 );
@@ -7,10 +7,9 @@
 //! `MessageTransmitter` contract, which handles cross-chain message verification
 //! and processing.
 
-use alloy_network::Ethereum;
+use alloy_network::{Ethereum, Network};
 use alloy_primitives::{Address, Bytes};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::sol;
 use tracing::{debug, info};
 
@@ -19,11 +18,11 @@ use MessageTransmitter::MessageTransmitterInstance;
 /// The CCTP v1 Message Transmitter contract wrapper
 ///
 /// Handles message verification and reception for cross-chain transfers.
-pub struct MessageTransmitterContract<P: Provider<Ethereum>> {
-    instance: MessageTransmitterInstance<P>,
+pub struct MessageTransmitterContract<P: Provider<N>, N: Network = Ethereum> {
+    instance: MessageTransmitterInstance<P, N>,
 }
 
-impl<P: Provider<Ethereum>> MessageTransmitterContract<P> {
+impl<P: Provider<N>, N: Network> MessageTransmitterContract<P, N> {
     /// Create a new `MessageTransmitterContract`
     pub fn new(address: Address, provider: P) -> Self {
         debug!(
@@ -31,7 +30,7 @@ impl<P: Provider<Ethereum>> MessageTransmitterContract<P> {
             event = "message_transmitter_contract_initialized"
         );
         Self {
-            instance: MessageTransmitterInstance::<P>::new(address, provider),
+            instance: MessageTransmitterInstance::new(address, provider),
         }
     }
 
@@ -47,7 +46,7 @@ impl<P: Provider<Ethereum>> MessageTransmitterContract<P> {
         message: Bytes,
         attestation: Bytes,
         from_address: Address,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             message_len = message.len(),
             attestation_len = attestation.len(),
@@ -63,6 +62,51 @@ impl<P: Provider<Ethereum>> MessageTransmitterContract<P> {
             .into_transaction_request()
     }
 
+    /// Create transaction request for replacing a previously sent message
+    ///
+    /// Re-emits the original message with a new body and destination caller,
+    /// keeping the original nonce. Must be sent on the source chain by the
+    /// original message sender. For burn messages, prefer
+    /// `TokenMessengerContract::replace_deposit_for_burn_transaction`, which
+    /// builds the new body for you.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_message` - The original message bytes
+    /// * `original_attestation` - Circle's attestation for the original message
+    /// * `new_message_body` - The replacement message body
+    /// * `new_destination_caller` - New authorized caller on destination (0x0 = anyone)
+    /// * `from_address` - Address that will submit the transaction
+    pub fn replace_message_transaction(
+        &self,
+        original_message: Bytes,
+        original_attestation: Bytes,
+        new_message_body: Bytes,
+        new_destination_caller: Address,
+        from_address: Address,
+    ) -> N::TransactionRequest {
+        info!(
+            message_len = original_message.len(),
+            attestation_len = original_attestation.len(),
+            new_message_body_len = new_message_body.len(),
+            new_destination_caller = %new_destination_caller,
+            from_address = %from_address,
+            contract_address = %self.instance.address(),
+            version = "v1",
+            event = "replace_message_transaction_created"
+        );
+
+        self.instance
+            .replaceMessage(
+                original_message,
+                original_attestation,
+                new_message_body,
+                new_destination_caller.into_word(),
+            )
+            .from(from_address)
+            .into_transaction_request()
+    }
+
     /// Check if a message nonce has been used (anti-replay protection)
     ///
     /// Queries the `usedNonces` mapping to determine if a nonce has already
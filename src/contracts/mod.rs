@@ -14,10 +14,11 @@
 //! Contract wrappers provide type-safe, instrumented interfaces to CCTP contracts:
 //!
 //! - v1: [`TokenMessengerContract`](token_messenger::TokenMessengerContract), [`MessageTransmitterContract`](message_transmitter::MessageTransmitterContract)
-//! - v2: [`TokenMessengerV2Contract`](v2::TokenMessengerV2Contract), [`MessageTransmitterV2Contract`](v2::MessageTransmitterV2Contract)
+//! - v2: [`TokenMessengerV2Contract`](v2::TokenMessengerV2Contract), [`MessageTransmitterV2Contract`](v2::MessageTransmitterV2Contract), [`TokenMinterV2Contract`](v2::TokenMinterV2Contract)
 //! - ERC20: [`Erc20Contract`](erc20::Erc20Contract) for approval and allowance operations
 
 pub mod erc20;
 pub mod message_transmitter;
+pub(crate) mod revert;
 pub mod token_messenger;
 pub mod v2;
@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Revert-data decoding for the CCTP contracts and the tokens they move.
+//!
+//! The `TokenMessengerV2`, `MessageTransmitterV2` and `TokenMinterV2` ABIs
+//! declare no custom errors: every CCTP check reverts with a `require`
+//! string, so their reverts arrive as `Error(string)` and are classified by
+//! reason. Tokens built on OpenZeppelin 5 revert with custom errors instead,
+//! which are decoded against the selectors declared below.
+
+use crate::error::{decode_revert_reason, ContractRevert};
+use alloy_primitives::Bytes;
+use alloy_sol_types::{sol, SolInterface};
+
+/// Decodes raw revert data into a [`ContractRevert`].
+///
+/// Tries the known custom errors first, then `Error(string)` /
+/// `Panic(uint256)`, and falls back to [`ContractRevert::Unknown`].
+/// Returns `None` for empty revert data, which carries no reason.
+pub(crate) fn decode_revert_data(data: &[u8]) -> Option<ContractRevert> {
+    if data.is_empty() {
+        return None;
+    }
+
+    if let Ok(error) = TokenErrors::TokenErrorsErrors::abi_decode(data) {
+        return Some(match error {
+            TokenErrors::TokenErrorsErrors::ERC20InsufficientAllowance(_) => {
+                ContractRevert::InsufficientAllowance
+            }
+            TokenErrors::TokenErrorsErrors::ERC20InsufficientBalance(_) => {
+                ContractRevert::InsufficientBalance
+            }
+            TokenErrors::TokenErrorsErrors::EnforcedPause(_) => ContractRevert::Paused,
+        });
+    }
+
+    Some(match decode_revert_reason(data) {
+        Some(reason) => ContractRevert::from_reason(&reason),
+        None => ContractRevert::Unknown(Bytes::copy_from_slice(data)),
+    })
+}
+
+sol!(
+    #[allow(missing_docs)]
+    interface TokenErrors {
+        error ERC20InsufficientAllowance(address spender, uint256 allowance, uint256 needed);
+        error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+        error EnforcedPause();
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256};
+    use alloy_sol_types::{Panic, Revert, SolError};
+
+    #[test]
+    fn test_decodes_custom_errors() {
+        let data = TokenErrors::ERC20InsufficientAllowance {
+            spender: Address::ZERO,
+            allowance: U256::ZERO,
+            needed: U256::from(1_000_000),
+        }
+        .abi_encode();
+        assert_eq!(
+            decode_revert_data(&data),
+            Some(ContractRevert::InsufficientAllowance)
+        );
+
+        let data = TokenErrors::ERC20InsufficientBalance {
+            sender: Address::ZERO,
+            balance: U256::ZERO,
+            needed: U256::from(1_000_000),
+        }
+        .abi_encode();
+        assert_eq!(
+            decode_revert_data(&data),
+            Some(ContractRevert::InsufficientBalance)
+        );
+
+        let data = TokenErrors::EnforcedPause {}.abi_encode();
+        assert_eq!(decode_revert_data(&data), Some(ContractRevert::Paused));
+    }
+
+    #[test]
+    fn test_decodes_require_strings() {
+        let data = Revert::from("Nonce already used").abi_encode();
+        assert_eq!(
+            decode_revert_data(&data),
+            Some(ContractRevert::NonceAlreadyUsed)
+        );
+    }
+
+    #[test]
+    fn test_panic_is_other() {
+        let data = Panic::from(0x11).abi_encode();
+        assert!(matches!(
+            decode_revert_data(&data),
+            Some(ContractRevert::Other(reason)) if reason.starts_with("panic:")
+        ));
+    }
+}
@@ -10,22 +10,24 @@
 use std::marker::PhantomData;
 
 use alloy_contract::CallBuilder;
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, U256};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, Bytes, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::sol;
 use tracing::{debug, info};
-use TokenMessenger::{depositForBurnCall, TokenMessengerInstance};
+use TokenMessenger::{
+    depositForBurnCall, depositForBurnWithCallerCall, replaceDepositForBurnCall,
+    TokenMessengerInstance,
+};
 
 use crate::spans;
 
 /// The CCTP v1 Token Messenger contract wrapper
-pub struct TokenMessengerContract<P: Provider<Ethereum>> {
-    instance: TokenMessengerInstance<P>,
+pub struct TokenMessengerContract<P: Provider<N>, N: Network = Ethereum> {
+    instance: TokenMessengerInstance<P, N>,
 }
 
-impl<P: Provider<Ethereum>> TokenMessengerContract<P> {
+impl<P: Provider<N>, N: Network> TokenMessengerContract<P, N> {
     /// Create a new `TokenMessengerContract`.
     pub fn new(address: Address, provider: P) -> Self {
         debug!(
@@ -47,7 +49,7 @@ impl<P: Provider<Ethereum>> TokenMessengerContract<P> {
         destination_domain: u32,
         token_address: Address,
         amount: U256,
-    ) -> CallBuilder<&P, PhantomData<depositForBurnCall>> {
+    ) -> CallBuilder<&P, PhantomData<depositForBurnCall>, N> {
         self.instance
             .depositForBurn(
                 amount,
@@ -68,7 +70,7 @@ impl<P: Provider<Ethereum>> TokenMessengerContract<P> {
         destination_domain: u32,
         token_address: Address,
         amount: U256,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         let span = spans::deposit_for_burn(
             &from_address,
             &recipient,
@@ -97,6 +99,142 @@ impl<P: Provider<Ethereum>> TokenMessengerContract<P> {
         )
         .into_transaction_request()
     }
+
+    /// Create the call builder for the `depositForBurnWithCaller` function.
+    ///
+    /// Most users will want to use the `deposit_for_burn_with_caller_transaction` function instead.
+    pub fn deposit_for_burn_with_caller_call_builder(
+        &self,
+        from_address: Address,
+        recipient: Address,
+        destination_domain: u32,
+        token_address: Address,
+        amount: U256,
+        destination_caller: Address,
+    ) -> CallBuilder<&P, PhantomData<depositForBurnWithCallerCall>, N> {
+        self.instance
+            .depositForBurnWithCaller(
+                amount,
+                destination_domain,
+                recipient.into_word(),
+                token_address,
+                destination_caller.into_word(),
+            )
+            .from(from_address)
+    }
+
+    /// Create the transaction request for the `depositForBurnWithCaller` function.
+    ///
+    /// Like `depositForBurn`, but only `destination_caller` may call `receiveMessage`
+    /// for the resulting message on the destination chain.
+    pub fn deposit_for_burn_with_caller_transaction(
+        &self,
+        from_address: Address,
+        recipient: Address,
+        destination_domain: u32,
+        token_address: Address,
+        amount: U256,
+        destination_caller: Address,
+    ) -> N::TransactionRequest {
+        let span = spans::deposit_for_burn(
+            &from_address,
+            &recipient,
+            destination_domain,
+            &token_address,
+            &amount,
+        );
+        let _guard = span.enter();
+
+        info!(
+            from_address = %from_address,
+            recipient = %recipient,
+            destination_domain = destination_domain,
+            token_address = %token_address,
+            amount = %amount,
+            destination_caller = %destination_caller,
+            contract_address = %self.instance.address(),
+            event = "deposit_for_burn_with_caller_transaction_created"
+        );
+
+        self.deposit_for_burn_with_caller_call_builder(
+            from_address,
+            recipient,
+            destination_domain,
+            token_address,
+            amount,
+            destination_caller,
+        )
+        .into_transaction_request()
+    }
+
+    /// Create the call builder for the `replaceDepositForBurn` function.
+    ///
+    /// Most users will want to use the `replace_deposit_for_burn_transaction` function instead.
+    pub fn replace_deposit_for_burn_call_builder(
+        &self,
+        from_address: Address,
+        original_message: Bytes,
+        original_attestation: Bytes,
+        new_destination_caller: Address,
+        new_mint_recipient: Address,
+    ) -> CallBuilder<&P, PhantomData<replaceDepositForBurnCall>, N> {
+        self.instance
+            .replaceDepositForBurn(
+                original_message,
+                original_attestation,
+                new_destination_caller.into_word(),
+                new_mint_recipient.into_word(),
+            )
+            .from(from_address)
+    }
+
+    /// Create the transaction request for the `replaceDepositForBurn` function.
+    ///
+    /// Re-emits a pending burn message with a new mint recipient and destination
+    /// caller. Must be sent on the source chain by the original message sender,
+    /// and the original attestation must still be valid. The amount, token and
+    /// nonce are carried over from the original message; only the original
+    /// or the replacement can ever be received on the destination chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_address` - The original burn sender
+    /// * `original_message` - The message bytes emitted by the original burn
+    /// * `original_attestation` - Circle's attestation for the original message
+    /// * `new_destination_caller` - New authorized caller on destination (0x0 = anyone)
+    /// * `new_mint_recipient` - New recipient of the minted USDC
+    pub fn replace_deposit_for_burn_transaction(
+        &self,
+        from_address: Address,
+        original_message: Bytes,
+        original_attestation: Bytes,
+        new_destination_caller: Address,
+        new_mint_recipient: Address,
+    ) -> N::TransactionRequest {
+        info!(
+            from_address = %from_address,
+            message_len = original_message.len(),
+            attestation_len = original_attestation.len(),
+            new_destination_caller = %new_destination_caller,
+            new_mint_recipient = %new_mint_recipient,
+            contract_address = %self.instance.address(),
+            event = "replace_deposit_for_burn_transaction_created"
+        );
+
+        self.replace_deposit_for_burn_call_builder(
+            from_address,
+            original_message,
+            original_attestation,
+            new_destination_caller,
+            new_mint_recipient,
+        )
+        .into_transaction_request()
+    }
+
+    /// Returns the contract address
+    pub fn address(&self) -> Address {
+        *self.instance.address()
+    }
 }
 
 sol!(
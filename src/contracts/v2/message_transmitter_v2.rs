@@ -7,10 +7,9 @@
 //! `MessageTransmitter` contract, which handles cross-chain message verification
 //! and reception with finality-aware processing.
 
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, Bytes};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::sol;
 use tracing::{debug, info};
 
@@ -21,11 +20,11 @@ use MessageTransmitterV2::MessageTransmitterV2Instance;
 ///
 /// Handles message verification and reception with support for different
 /// finality levels (Fast Transfer vs Standard).
-pub struct MessageTransmitterV2Contract<P: Provider<Ethereum>> {
-    instance: MessageTransmitterV2Instance<P>,
+pub struct MessageTransmitterV2Contract<P: Provider<N>, N: Network = Ethereum> {
+    instance: MessageTransmitterV2Instance<P, N>,
 }
 
-impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
+impl<P: Provider<N>, N: Network> MessageTransmitterV2Contract<P, N> {
     /// Create a new `MessageTransmitterV2Contract`
     pub fn new(address: Address, provider: P) -> Self {
         debug!(
@@ -33,7 +32,7 @@ impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
             event = "message_transmitter_v2_contract_initialized"
         );
         Self {
-            instance: MessageTransmitterV2Instance::<P>::new(address, provider),
+            instance: MessageTransmitterV2Instance::new(address, provider),
         }
     }
 
@@ -57,7 +56,7 @@ impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
         message: Bytes,
         attestation: Bytes,
         from_address: Address,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             message_len = message.len(),
             attestation_len = attestation.len(),
@@ -93,7 +92,7 @@ impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
         message_body: Bytes,
         destination_caller: Address,
         min_finality_threshold: u32,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             from_address = %from_address,
             destination_domain = %destination_domain,
@@ -120,19 +119,20 @@ impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
 
     /// Check if a message has been received (anti-replay protection)
     ///
-    /// Queries the `usedNonces` mapping to determine if a message has already
-    /// been processed. A non-zero value indicates the message was received.
+    /// Queries the `usedNonces` mapping, which is keyed by the 32-byte nonce
+    /// from the message header (see [`MessageHeader::nonce`](crate::MessageHeader)).
+    /// A non-zero value indicates the message was received.
     ///
     /// This is useful for checking replay protection before attempting to
     /// receive a message on the destination chain.
     pub async fn is_message_received(
         &self,
-        message_hash: [u8; 32],
+        nonce: FixedBytes<32>,
     ) -> Result<bool, alloy_contract::Error> {
-        let nonce_status = self.instance.usedNonces(message_hash.into()).call().await?;
+        let nonce_status = self.instance.usedNonces(nonce).call().await?;
 
         debug!(
-            message_hash = ?message_hash,
+            nonce = %nonce,
             nonce_status = %nonce_status,
             is_received = !nonce_status.is_zero(),
             event = "is_message_received_checked"
@@ -142,6 +142,26 @@ impl<P: Provider<Ethereum>> MessageTransmitterV2Contract<P> {
         Ok(!nonce_status.is_zero())
     }
 
+    /// CCTP domain this transmitter is deployed on
+    pub async fn local_domain(&self) -> Result<u32, alloy_contract::Error> {
+        self.instance.localDomain().call().await
+    }
+
+    /// Message format version the transmitter sends and accepts
+    pub async fn version(&self) -> Result<u32, alloy_contract::Error> {
+        self.instance.version().call().await
+    }
+
+    /// Number of attester signatures a message needs to be received
+    pub async fn signature_threshold(&self) -> Result<U256, alloy_contract::Error> {
+        self.instance.signatureThreshold().call().await
+    }
+
+    /// Largest message body, in bytes, the transmitter will send
+    pub async fn max_message_body_size(&self) -> Result<U256, alloy_contract::Error> {
+        self.instance.maxMessageBodySize().call().await
+    }
+
     /// Returns the contract address
     pub fn address(&self) -> Address {
         *self.instance.address()
@@ -8,6 +8,8 @@
 
 mod message_transmitter_v2;
 mod token_messenger_v2;
+mod token_minter_v2;
 
 pub use message_transmitter_v2::{MessageTransmitterV2, MessageTransmitterV2Contract};
 pub use token_messenger_v2::{TokenMessengerV2, TokenMessengerV2Contract};
+pub use token_minter_v2::{TokenMinterV2, TokenMinterV2Contract};
@@ -9,10 +9,9 @@
 
 #![allow(dead_code)] // Public API methods not used internally
 
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::sol;
 use tracing::{debug, info};
 
@@ -24,11 +23,11 @@ use TokenMessengerV2::TokenMessengerV2Instance;
 ///
 /// Supports v2 features including Fast Transfer (with fees) and programmable hooks.
 #[allow(dead_code)]
-pub struct TokenMessengerV2Contract<P: Provider<Ethereum>> {
-    instance: TokenMessengerV2Instance<P>,
+pub struct TokenMessengerV2Contract<P: Provider<N>, N: Network = Ethereum> {
+    instance: TokenMessengerV2Instance<P, N>,
 }
 
-impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
+impl<P: Provider<N>, N: Network> TokenMessengerV2Contract<P, N> {
     /// Create a new `TokenMessengerV2Contract`
     #[allow(dead_code)]
     pub fn new(address: Address, provider: P) -> Self {
@@ -67,7 +66,7 @@ impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
         max_fee: U256,
         min_finality_threshold: u32,
         destination_caller: Address,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         self.instance
             .depositForBurn(
                 amount,
@@ -93,7 +92,7 @@ impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
         destination_domain: DomainId,
         token_address: Address,
         amount: U256,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         let span = spans::deposit_for_burn(
             &from_address,
             &recipient,
@@ -152,7 +151,7 @@ impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
         token_address: Address,
         amount: U256,
         max_fee: U256,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             from_address = %from_address,
             recipient = %recipient,
@@ -203,7 +202,7 @@ impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
         token_address: Address,
         amount: U256,
         hook_data: Bytes,
-    ) -> TransactionRequest {
+    ) -> N::TransactionRequest {
         info!(
             from_address = %from_address,
             recipient = %recipient,
@@ -233,6 +232,42 @@ impl<P: Provider<Ethereum>> TokenMessengerV2Contract<P> {
             .into_transaction_request()
     }
 
+    /// Address of the `TokenMinterV2` this messenger burns and mints through
+    pub async fn local_minter(&self) -> Result<Address, alloy_contract::Error> {
+        let minter = self.instance.localMinter().call().await?;
+
+        debug!(
+            local_minter = %minter,
+            contract_address = %self.instance.address(),
+            event = "local_minter_discovered"
+        );
+
+        Ok(minter)
+    }
+
+    /// Burn message body version this messenger sends and accepts
+    pub async fn message_body_version(&self) -> Result<u32, alloy_contract::Error> {
+        self.instance.messageBodyVersion().call().await
+    }
+
+    /// Address of the `MessageTransmitterV2` this messenger sends through
+    pub async fn local_message_transmitter(&self) -> Result<Address, alloy_contract::Error> {
+        self.instance.localMessageTransmitter().call().await
+    }
+
+    /// Token messenger trusted on `domain`, as a 32-byte address
+    ///
+    /// Returns zero if `domain` is not linked.
+    pub async fn remote_token_messenger(
+        &self,
+        domain: DomainId,
+    ) -> Result<FixedBytes<32>, alloy_contract::Error> {
+        self.instance
+            .remoteTokenMessengers(domain.as_u32())
+            .call()
+            .await
+    }
+
     /// Returns the contract address
     pub fn address(&self) -> Address {
         *self.instance.address()
@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! `TokenMinterV2` contract bindings and wrapper
+//!
+//! This module contains the Alloy-generated contract bindings for the CCTP v2
+//! `TokenMinter` contract, which holds the per-message burn limits and the
+//! mapping between remote and local tokens.
+
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::sol;
+use tracing::debug;
+
+use crate::protocol::DomainId;
+use TokenMinterV2::TokenMinterV2Instance;
+
+/// The CCTP v2 Token Minter contract wrapper
+///
+/// Read-only access to the limits and token pairs enforced when the
+/// `TokenMessengerV2` burns and mints. Discover its address with
+/// [`TokenMessengerV2Contract::local_minter`](super::TokenMessengerV2Contract::local_minter).
+pub struct TokenMinterV2Contract<P: Provider<N>, N: Network = Ethereum> {
+    instance: TokenMinterV2Instance<P, N>,
+}
+
+impl<P: Provider<N>, N: Network> TokenMinterV2Contract<P, N> {
+    /// Create a new `TokenMinterV2Contract`
+    pub fn new(address: Address, provider: P) -> Self {
+        debug!(
+            contract_address = %address,
+            event = "token_minter_v2_contract_initialized"
+        );
+        Self {
+            instance: TokenMinterV2Instance::new(address, provider),
+        }
+    }
+
+    /// Maximum amount of `token` that can be burned in a single message
+    ///
+    /// A zero limit means the token is not enabled for burning.
+    pub async fn burn_limit_per_message(
+        &self,
+        token: Address,
+    ) -> Result<U256, alloy_contract::Error> {
+        let limit = self.instance.burnLimitsPerMessage(token).call().await?;
+
+        debug!(
+            token = %token,
+            burn_limit = %limit,
+            contract_address = %self.instance.address(),
+            event = "burn_limit_per_message_checked"
+        );
+
+        Ok(limit)
+    }
+
+    /// Local token linked to `remote_token` on `remote_domain`
+    ///
+    /// Returns `Address::ZERO` if the pair is not linked.
+    pub async fn get_local_token(
+        &self,
+        remote_domain: DomainId,
+        remote_token: FixedBytes<32>,
+    ) -> Result<Address, alloy_contract::Error> {
+        self.instance
+            .getLocalToken(remote_domain.as_u32(), remote_token)
+            .call()
+            .await
+    }
+
+    /// Local token for a raw `keccak256(remoteDomain, remoteToken)` key
+    ///
+    /// Prefer [`Self::get_local_token`] unless the key is already at hand.
+    pub async fn remote_tokens_to_local_tokens(
+        &self,
+        key: FixedBytes<32>,
+    ) -> Result<Address, alloy_contract::Error> {
+        self.instance.remoteTokensToLocalTokens(key).call().await
+    }
+
+    /// Whether the minter is paused, blocking all burns and mints
+    pub async fn paused(&self) -> Result<bool, alloy_contract::Error> {
+        self.instance.paused().call().await
+    }
+
+    /// Returns the contract address
+    pub fn address(&self) -> Address {
+        *self.instance.address()
+    }
+}
+
+sol!(
+    #[allow(clippy::too_many_arguments)]
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    TokenMinterV2,
+    "abis/v2/token_minter_v2.json"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mocked, word};
+    use alloy_primitives::keccak256;
+    use alloy_sol_types::SolValue;
+
+    #[tokio::test]
+    async fn test_decodes_minter_reads() {
+        let (asserter, provider) = mocked();
+        let minter =
+            TokenMinterV2Contract::<_, Ethereum>::new(Address::repeat_byte(0x0d), provider);
+        let usdc = Address::repeat_byte(0x11);
+        let remote_usdc = Address::repeat_byte(0x22).into_word();
+
+        asserter.push_success(&word(U256::from(10_000_000_000_000u64)));
+        asserter.push_success(&word(usdc));
+        asserter.push_success(&word(Address::ZERO));
+        asserter.push_success(&word(usdc));
+        asserter.push_success(&word(true));
+
+        assert_eq!(
+            minter.burn_limit_per_message(usdc).await.unwrap(),
+            U256::from(10_000_000_000_000u64)
+        );
+        assert_eq!(
+            minter
+                .get_local_token(DomainId::Ethereum, remote_usdc)
+                .await
+                .unwrap(),
+            usdc
+        );
+        // An unlinked pair reads as the zero address
+        assert_eq!(
+            minter
+                .get_local_token(DomainId::Linea, remote_usdc)
+                .await
+                .unwrap(),
+            Address::ZERO
+        );
+        let key = keccak256((0u32, remote_usdc).abi_encode_packed());
+        assert_eq!(
+            minter.remote_tokens_to_local_tokens(key).await.unwrap(),
+            usdc
+        );
+        assert!(minter.paused().await.unwrap());
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_failure_is_an_error() {
+        let (asserter, provider) = mocked();
+        let minter =
+            TokenMinterV2Contract::<_, Ethereum>::new(Address::repeat_byte(0x0d), provider);
+
+        asserter.push_failure_msg("execution reverted");
+        assert!(minter.paused().await.is_err());
+    }
+}
@@ -3,7 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloy_json_rpc::RpcError;
-use alloy_primitives::TxHash;
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+use alloy_sol_types::{ContractError, GenericRevertReason, RevertReason};
 use alloy_transport::TransportErrorKind;
 use std::fmt;
 use thiserror::Error;
@@ -44,12 +45,199 @@ impl fmt::Display for AttestationFailureKind {
     }
 }
 
+/// Revert phrases emitted by the CCTP v2 contracts and the USDC token,
+/// mapped to the categorical reason they represent. Matched
+/// case-insensitively as substrings of the decoded `Error(string)` reason.
+const REVERT_REASON_PATTERNS: &[(&str, ContractRevert)] = &[
+    ("denylist", ContractRevert::Denylisted),
+    ("blacklisted", ContractRevert::Denylisted),
+    ("exceeds per tx limit", ContractRevert::BurnLimitExceeded),
+    ("exceeds allowance", ContractRevert::InsufficientAllowance),
+    (
+        "insufficient allowance",
+        ContractRevert::InsufficientAllowance,
+    ),
+    ("exceeds balance", ContractRevert::InsufficientBalance),
+    ("insufficient balance", ContractRevert::InsufficientBalance),
+    ("paused", ContractRevert::Paused),
+    ("invalid signature", ContractRevert::InvalidAttestation),
+    ("invalid attestation", ContractRevert::InvalidAttestation),
+    ("nonce already used", ContractRevert::NonceAlreadyUsed),
+    ("expired", ContractRevert::MessageExpired),
+    (
+        "invalid caller for message",
+        ContractRevert::InvalidDestinationCaller,
+    ),
+];
+
+/// Decodes `Error(string)`, `Panic(uint256)` or raw UTF-8 revert data.
+///
+/// Unlike [`alloy_sol_types::decode_revert_reason`], an `Error(string)` yields
+/// the bare reason without a `revert: ` prefix.
+pub(crate) fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    match GenericRevertReason::decode(data)? {
+        RevertReason::ContractError(ContractError::Revert(revert)) => Some(revert.reason),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Prefix nodes put in front of the revert reason in error messages.
+const EXECUTION_REVERTED: &str = "execution reverted";
+
+/// Categorical reasons a CCTP contract call reverts.
+///
+/// Carried by [`CctpError::Reverted`] and [`CctpError::SimulationReverted`],
+/// and available for any error via [`CctpError::contract_revert`], so callers
+/// can react to specific failures without substring-matching on a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractRevert {
+    /// The sender or recipient is on the `TokenMessengerV2` denylist or the
+    /// USDC blacklist.
+    Denylisted,
+    /// The burn amount exceeds `burnLimitsPerMessage` on the `TokenMinterV2`.
+    BurnLimitExceeded,
+    /// The `TokenMessengerV2` allowance does not cover the burn amount.
+    InsufficientAllowance,
+    /// The sender's token balance does not cover the burn amount.
+    InsufficientBalance,
+    /// The contract is paused.
+    Paused,
+    /// The attestation signatures did not verify against the attesters.
+    InvalidAttestation,
+    /// The message nonce was already used on the destination chain.
+    NonceAlreadyUsed,
+    /// The message passed its expiration block and must be re-attested.
+    MessageExpired,
+    /// The message restricts `destinationCaller` to a different address.
+    InvalidDestinationCaller,
+    /// An `Error(string)` or `Panic(uint256)` revert with no known category.
+    Other(String),
+    /// Revert data that could not be decoded.
+    Unknown(Bytes),
+}
+
+impl ContractRevert {
+    /// Classifies a decoded revert reason string.
+    pub fn from_reason(reason: &str) -> Self {
+        let lower = reason.to_lowercase();
+        REVERT_REASON_PATTERNS
+            .iter()
+            .find(|(pattern, _)| lower.contains(pattern))
+            .map(|(_, revert)| revert.clone())
+            .unwrap_or_else(|| Self::Other(reason.to_string()))
+    }
+
+    /// Decodes and classifies raw revert data.
+    ///
+    /// Recognises the OpenZeppelin token custom errors as well as
+    /// `Error(string)` and `Panic(uint256)`. Returns `None` for empty revert
+    /// data, which carries no reason.
+    pub fn from_revert_data(data: &[u8]) -> Option<Self> {
+        crate::contracts::revert::decode_revert_data(data)
+    }
+
+    /// Extracts the revert from an RPC error, if the error is one.
+    ///
+    /// Prefers the structured revert data and falls back to the reason
+    /// embedded in an `execution reverted: ...` message for nodes that omit
+    /// the data field.
+    pub fn from_rpc_error(error: &RpcError<TransportErrorKind>) -> Option<Self> {
+        let payload = error.as_error_resp()?;
+
+        if let Some(revert) = payload
+            .as_revert_data()
+            .and_then(|data| Self::from_revert_data(&data))
+        {
+            return Some(revert);
+        }
+
+        let message = payload.message.as_ref();
+        let reason = message
+            .to_lowercase()
+            .starts_with(EXECUTION_REVERTED)
+            .then(|| {
+                message[EXECUTION_REVERTED.len()..]
+                    .trim_start_matches(':')
+                    .trim()
+            })?;
+
+        Some(if reason.is_empty() {
+            Self::Unknown(Bytes::new())
+        } else {
+            Self::from_reason(reason)
+        })
+    }
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denylisted => f.write_str("sender or recipient is denylisted"),
+            Self::BurnLimitExceeded => {
+                f.write_str("amount exceeds the per-message burn limit; split the transfer")
+            }
+            Self::InsufficientAllowance => {
+                f.write_str("insufficient allowance; approve the TokenMessenger first")
+            }
+            Self::InsufficientBalance => f.write_str("insufficient token balance"),
+            Self::Paused => f.write_str("contract is paused"),
+            Self::InvalidAttestation => f.write_str("attestation is invalid for this message"),
+            Self::NonceAlreadyUsed => f.write_str("message nonce already used"),
+            Self::MessageExpired => f.write_str("message expired and must be re-attested"),
+            Self::InvalidDestinationCaller => {
+                f.write_str("sender is not the message's destination caller")
+            }
+            Self::Other(reason) => write!(f, "reverted: {reason}"),
+            Self::Unknown(data) => write!(f, "reverted with undecoded data {data}"),
+        }
+    }
+}
+
+/// A bridge transaction that was mined but reverted.
+///
+/// Carried by [`CctpError::ApprovalReverted`], [`CctpError::BurnReverted`] and
+/// [`CctpError::MintReverted`]. The revert data is recovered on a best-effort
+/// basis by replaying the transaction, so it may be absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertedTransaction {
+    /// Hash of the reverted transaction.
+    pub tx_hash: TxHash,
+    /// Block the transaction was included in.
+    pub block_number: Option<u64>,
+    /// Gas consumed before the revert.
+    pub gas_used: u64,
+    /// Raw revert data returned by the replayed call, if any.
+    pub revert_data: Option<Bytes>,
+    /// Revert reason decoded from `Error(string)` or `Panic(uint256)` data.
+    pub reason: Option<String>,
+}
+
+impl fmt::Display for RevertedTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tx_hash)?;
+        if let Some(block_number) = self.block_number {
+            write!(f, " in block {block_number}")?;
+        }
+        match (&self.reason, &self.revert_data) {
+            (Some(reason), _) => write!(f, ": {reason}"),
+            (None, Some(data)) if !data.is_empty() => write!(f, ": revert data {data}"),
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum CctpError {
     #[error("Unsupported chain: {0:?}")]
     UnsupportedChain(alloy_chains::NamedChain),
 
+    /// The chain id has no registered record, or its record lacks the
+    /// requested CCTP deployment.
+    #[error("Unsupported chain id: {0}")]
+    UnsupportedChainId(u64),
+
     #[error("Message already relayed (transfer successful via third party): {original}")]
     AlreadyRelayed { original: String },
 
@@ -63,7 +251,7 @@ pub enum CctpError {
     /// structured introspection so callers can use [`alloy_contract::Error::as_revert_data`]
     /// and [`alloy_contract::Error::as_decoded_interface_error`] for revert decoding.
     #[error(transparent)]
-    Contract(#[from] alloy_contract::Error),
+    Contract(alloy_contract::Error),
 
     #[error("Attestation failed: {0}")]
     AttestationFailed(AttestationFailureKind),
@@ -84,14 +272,80 @@ pub enum CctpError {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    /// The amount exceeds the `TokenMinterV2` per-message burn limit for
+    /// the token. Split the transfer into chunks of at most `limit`.
+    #[error("Burn amount {amount} exceeds the per-message limit of {limit}")]
+    BurnLimitExceeded { amount: U256, limit: U256 },
+
+    /// The built-in USDC address of a chain disagrees with the token the
+    /// `TokenMinterV2` has linked to it.
+    #[error("USDC address mismatch: expected {expected}, TokenMinterV2 links {actual}")]
+    UsdcAddressMismatch { expected: Address, actual: Address },
+
+    /// The message bytes are not a well-formed CCTP v2 message.
+    #[error(transparent)]
+    InvalidMessage(#[from] crate::ParseMessageError),
+
+    /// The message still carries the all-zero placeholder nonce from the
+    /// `MessageSent` event log. Only the canonical message returned by the
+    /// Iris API has the real nonce that `usedNonces` is keyed by.
+    #[error("Message has a placeholder nonce; use the canonical message from the Iris API")]
+    PlaceholderNonce,
+
+    /// The ERC20 approval transaction was mined but reverted.
+    #[error("Approval transaction reverted: {0}")]
+    ApprovalReverted(RevertedTransaction),
+
+    /// The burn transaction was mined but reverted, so no `MessageSent`
+    /// event was emitted and there is nothing to attest.
+    #[error("Burn transaction reverted: {0}")]
+    BurnReverted(RevertedTransaction),
+
+    /// The mint (`receiveMessage`) transaction was mined but reverted.
+    #[error("Mint transaction reverted: {0}")]
+    MintReverted(RevertedTransaction),
+
+    /// A contract call reverted before any transaction was mined, typically
+    /// during gas estimation. `source` is the original RPC error.
+    #[error("Contract reverted: {revert}")]
+    Reverted {
+        revert: ContractRevert,
+        #[source]
+        source: RpcError<TransportErrorKind>,
+    },
+
+    /// A pre-flight `eth_call` of the transaction reverted; nothing was sent.
+    #[error("Simulation reverted: {0}")]
+    SimulationReverted(ContractRevert),
+
+    /// Waiting for a transaction receipt failed before the transaction
+    /// was confirmed.
+    #[error("Pending transaction error: {0}")]
+    PendingTransaction(#[from] alloy_provider::PendingTransactionError),
+
     #[error("Timeout waiting for attestation")]
     AttestationTimeout,
 
+    /// A stuck transaction's nonce was used by a transaction the bridge did
+    /// not send, so neither the original nor any replacement can be mined.
+    #[error("Nonce {nonce} of {from} was used by another transaction")]
+    NonceConsumed { from: Address, nonce: u64 },
+
+    /// A stuck transaction was not mined within the replacement policy's
+    /// `max_rounds`. `tx_hash` is the last replacement sent, or the
+    /// original if none was.
+    #[error("Transaction {tx_hash} was not mined after {rounds} replacement rounds")]
+    ReplacementRoundsExhausted { tx_hash: TxHash, rounds: u32 },
+
     #[error("Invalid URL: {0}")]
     InvalidUrl(#[from] url::ParseError),
 
     #[error("RPC error: {0}")]
-    Rpc(#[from] alloy_json_rpc::RpcError<alloy_transport::TransportErrorKind>),
+    Rpc(RpcError<TransportErrorKind>),
+
+    /// The ERC-4337 bundler returned a JSON-RPC error
+    #[error("Bundler error {code}: {message}")]
+    Bundler { code: i64, message: String },
 
     #[error("ABI encoding/decoding error: {0}")]
     Abi(#[from] alloy_sol_types::Error),
@@ -103,7 +357,63 @@ pub enum CctpError {
     Hex(#[from] alloy_primitives::hex::FromHexError),
 }
 
+impl From<RpcError<TransportErrorKind>> for CctpError {
+    /// Classifies revert responses into [`CctpError::Reverted`]; every other
+    /// RPC failure becomes [`CctpError::Rpc`].
+    fn from(error: RpcError<TransportErrorKind>) -> Self {
+        match ContractRevert::from_rpc_error(&error) {
+            Some(revert) => Self::Reverted {
+                revert,
+                source: error,
+            },
+            None => Self::Rpc(error),
+        }
+    }
+}
+
+impl From<alloy_contract::Error> for CctpError {
+    /// Transport errors carrying a revert become [`CctpError::Reverted`];
+    /// everything else stays a typed [`CctpError::Contract`].
+    fn from(error: alloy_contract::Error) -> Self {
+        match error {
+            alloy_contract::Error::TransportError(rpc_error)
+                if ContractRevert::from_rpc_error(&rpc_error).is_some() =>
+            {
+                rpc_error.into()
+            }
+            error => Self::Contract(error),
+        }
+    }
+}
+
 impl CctpError {
+    /// Returns the decoded contract revert behind this error, if any.
+    ///
+    /// Covers [`Reverted`](Self::Reverted) and
+    /// [`SimulationReverted`](Self::SimulationReverted) directly, the revert
+    /// data or reason of mined reverts such as
+    /// [`MintReverted`](Self::MintReverted), and revert payloads still
+    /// wrapped in [`Rpc`](Self::Rpc) or [`Contract`](Self::Contract).
+    pub fn contract_revert(&self) -> Option<ContractRevert> {
+        match self {
+            CctpError::Reverted { revert, .. } | CctpError::SimulationReverted(revert) => {
+                Some(revert.clone())
+            }
+            CctpError::ApprovalReverted(tx)
+            | CctpError::BurnReverted(tx)
+            | CctpError::MintReverted(tx) => tx
+                .revert_data
+                .as_ref()
+                .and_then(|data| ContractRevert::from_revert_data(data))
+                .or_else(|| tx.reason.as_deref().map(ContractRevert::from_reason)),
+            CctpError::Rpc(rpc_error)
+            | CctpError::Contract(alloy_contract::Error::TransportError(rpc_error)) => {
+                ContractRevert::from_rpc_error(rpc_error)
+            }
+            _ => None,
+        }
+    }
+
     /// Checks if this error indicates that a CCTP message was already relayed.
     ///
     /// This is common in CCTP v2 where third-party relayers may complete transfers
@@ -111,10 +421,11 @@ impl CctpError {
     /// (just not by us).
     ///
     /// Detects the explicit [`AlreadyRelayed`](Self::AlreadyRelayed) variant
-    /// directly; for [`Rpc`](Self::Rpc) and the transport-error subset of
-    /// [`Contract`](Self::Contract), inspects the RPC error payload's message
-    /// and data fields against known revert phrases for robustness across
-    /// different RPC providers.
+    /// directly, and otherwise relies on the decoded
+    /// [`contract_revert`](Self::contract_revert) being
+    /// [`ContractRevert::NonceAlreadyUsed`]. Only when no revert can be decoded
+    /// does it fall back to matching the RPC error payload's message and data
+    /// fields against known revert phrases.
     ///
     /// # Example
     ///
@@ -130,6 +441,24 @@ impl CctpError {
             // Explicit AlreadyRelayed variant
             CctpError::AlreadyRelayed { .. } => true,
 
+            // Only mints consume nonces
+            CctpError::ApprovalReverted(_) | CctpError::BurnReverted(_) => false,
+
+            _ => match self.contract_revert() {
+                Some(ContractRevert::NonceAlreadyUsed) => true,
+                // Unclassified reasons may still use a node-specific phrasing
+                Some(ContractRevert::Other(reason)) => {
+                    Self::message_matches_already_relayed(&reason)
+                }
+                Some(_) => false,
+                None => self.matches_already_relayed_heuristics(),
+            },
+        }
+    }
+
+    /// String-pattern fallback for errors without a decodable revert.
+    fn matches_already_relayed_heuristics(&self) -> bool {
+        match self {
             // Check RPC errors for execution revert with known patterns
             CctpError::Rpc(rpc_error) => Self::rpc_error_is_already_relayed(rpc_error),
 
@@ -173,7 +502,10 @@ impl CctpError {
     ///
     /// Useful for implementing retry logic for transient failures.
     pub fn is_timeout(&self) -> bool {
-        if matches!(self, CctpError::AttestationTimeout) {
+        if matches!(
+            self,
+            CctpError::AttestationTimeout | CctpError::ReplacementRoundsExhausted { .. }
+        ) {
             return true;
         }
 
@@ -222,6 +554,7 @@ pub type Result<T> = std::result::Result<T, CctpError>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     fn error_payload(message: &'static str) -> alloy_json_rpc::ErrorPayload {
         alloy_json_rpc::ErrorPayload {
@@ -253,6 +586,196 @@ mod tests {
         assert!(!check("some other error"));
     }
 
+    #[test]
+    fn test_mint_reverted_with_used_nonce_is_already_relayed() {
+        let revert = |reason: Option<&str>| RevertedTransaction {
+            tx_hash: TxHash::ZERO,
+            block_number: Some(100),
+            gas_used: 50_000,
+            revert_data: None,
+            reason: reason.map(str::to_string),
+        };
+
+        assert!(CctpError::MintReverted(revert(Some("Nonce already used"))).is_already_relayed());
+        assert!(!CctpError::MintReverted(revert(Some("Invalid signature"))).is_already_relayed());
+        assert!(!CctpError::MintReverted(revert(None)).is_already_relayed());
+        // Only mints can be already relayed
+        assert!(!CctpError::BurnReverted(revert(Some("Nonce already used"))).is_already_relayed());
+    }
+
+    #[test]
+    fn test_reverted_transaction_display() {
+        let mut revert = RevertedTransaction {
+            tx_hash: TxHash::ZERO,
+            block_number: Some(7),
+            gas_used: 50_000,
+            revert_data: Some(Bytes::from_static(&[0xde, 0xad])),
+            reason: None,
+        };
+        assert_eq!(
+            CctpError::BurnReverted(revert.clone()).to_string(),
+            format!(
+                "Burn transaction reverted: {} in block 7: revert data 0xdead",
+                TxHash::ZERO
+            ),
+        );
+
+        revert.reason = Some("Insufficient allowance".to_string());
+        assert_eq!(
+            CctpError::BurnReverted(revert).to_string(),
+            format!(
+                "Burn transaction reverted: {} in block 7: Insufficient allowance",
+                TxHash::ZERO
+            ),
+        );
+    }
+
+    #[rstest]
+    #[case("Denylistable: account is on denylist", ContractRevert::Denylisted)]
+    #[case("Blacklistable: account is blacklisted", ContractRevert::Denylisted)]
+    #[case("Burn amount exceeds per tx limit", ContractRevert::BurnLimitExceeded)]
+    #[case(
+        "ERC20: transfer amount exceeds allowance",
+        ContractRevert::InsufficientAllowance
+    )]
+    #[case(
+        "ERC20: transfer amount exceeds balance",
+        ContractRevert::InsufficientBalance
+    )]
+    #[case("Pausable: paused", ContractRevert::Paused)]
+    #[case("Invalid signature: not attester", ContractRevert::InvalidAttestation)]
+    #[case("Nonce already used", ContractRevert::NonceAlreadyUsed)]
+    #[case(
+        "Message expired and must be re-signed",
+        ContractRevert::MessageExpired
+    )]
+    #[case("Invalid caller for message", ContractRevert::InvalidDestinationCaller)]
+    fn test_contract_revert_from_reason(#[case] reason: &str, #[case] expected: ContractRevert) {
+        assert_eq!(ContractRevert::from_reason(reason), expected);
+    }
+
+    #[test]
+    fn test_contract_revert_from_revert_data() {
+        use alloy_sol_types::{Revert, SolError};
+
+        let data = Revert::from("Pausable: paused").abi_encode();
+        assert_eq!(
+            ContractRevert::from_revert_data(&data),
+            Some(ContractRevert::Paused)
+        );
+
+        let data = Revert::from("Amount must be nonzero").abi_encode();
+        assert_eq!(
+            ContractRevert::from_revert_data(&data),
+            Some(ContractRevert::Other("Amount must be nonzero".to_string()))
+        );
+
+        assert_eq!(
+            ContractRevert::from_revert_data(&[0xde, 0xad, 0xbe, 0xef, 0x01]),
+            Some(ContractRevert::Unknown(Bytes::from_static(&[
+                0xde, 0xad, 0xbe, 0xef, 0x01
+            ])))
+        );
+        assert_eq!(ContractRevert::from_revert_data(&[]), None);
+    }
+
+    #[test]
+    fn test_contract_revert_from_rpc_error_data() {
+        use alloy_sol_types::{Revert, SolError};
+
+        let data = format!(
+            "\"{}\"",
+            Bytes::from(Revert::from("Burn amount exceeds per tx limit").abi_encode())
+        );
+        let payload = alloy_json_rpc::ErrorPayload {
+            code: 3,
+            message: "execution reverted".into(),
+            data: Some(serde_json::value::RawValue::from_string(data).unwrap()),
+        };
+        assert_eq!(
+            ContractRevert::from_rpc_error(&RpcError::ErrorResp(payload)),
+            Some(ContractRevert::BurnLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn test_contract_revert_from_rpc_error_message_fallback() {
+        let revert = |message: &'static str| {
+            ContractRevert::from_rpc_error(&RpcError::ErrorResp(error_payload(message)))
+        };
+
+        assert_eq!(
+            revert("execution reverted: Nonce already used"),
+            Some(ContractRevert::NonceAlreadyUsed)
+        );
+        assert_eq!(
+            revert("execution reverted"),
+            Some(ContractRevert::Unknown(Bytes::new()))
+        );
+        assert_eq!(revert("header not found"), None);
+    }
+
+    #[test]
+    fn test_rpc_revert_converts_to_typed_variant() {
+        let err: CctpError =
+            RpcError::ErrorResp(error_payload("execution reverted: Pausable: paused")).into();
+        assert!(matches!(
+            err,
+            CctpError::Reverted {
+                revert: ContractRevert::Paused,
+                ..
+            }
+        ));
+        assert_eq!(err.contract_revert(), Some(ContractRevert::Paused));
+        assert!(!err.is_already_relayed());
+
+        let err: CctpError = RpcError::ErrorResp(error_payload("header not found")).into();
+        assert!(matches!(err, CctpError::Rpc(_)));
+        assert_eq!(err.contract_revert(), None);
+    }
+
+    #[test]
+    fn test_is_already_relayed_prefers_decoded_revert() {
+        use alloy_sol_types::{Revert, SolError};
+
+        // Decoded from revert data even though the message carries no phrase
+        let data = format!(
+            "\"{}\"",
+            Bytes::from(Revert::from("Nonce already used").abi_encode())
+        );
+        let payload = alloy_json_rpc::ErrorPayload {
+            code: 3,
+            message: "execution reverted".into(),
+            data: Some(serde_json::value::RawValue::from_string(data).unwrap()),
+        };
+        let err: CctpError = RpcError::ErrorResp(payload).into();
+        assert_eq!(
+            err.contract_revert(),
+            Some(ContractRevert::NonceAlreadyUsed)
+        );
+        assert!(err.is_already_relayed());
+
+        assert!(
+            CctpError::SimulationReverted(ContractRevert::NonceAlreadyUsed).is_already_relayed()
+        );
+        assert!(
+            !CctpError::SimulationReverted(ContractRevert::MessageExpired).is_already_relayed()
+        );
+    }
+
+    #[test]
+    fn test_burn_limit_exceeded_reports_limit() {
+        let err = CctpError::BurnLimitExceeded {
+            amount: U256::from(2_000_000u64),
+            limit: U256::from(1_000_000u64),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Burn amount 2000000 exceeds the per-message limit of 1000000"
+        );
+        assert!(!err.is_already_relayed());
+    }
+
     #[test]
     fn test_is_timeout() {
         let err = CctpError::AttestationTimeout;
@@ -16,8 +16,26 @@
 //! | Bridge USDC on a v1-only legacy chain               | [`Cctp`]                            |
 //! | Self-relay safely against permissionless relayers   | [`CctpV2Bridge::mint_if_needed`]    |
 //! | Wait for any relayer (cheapest happy path)          | [`CctpV2Bridge::wait_for_receive`]  |
+//! | Survive restarts mid-transfer                       | [`Transfer`] + [`TransferStore`]    |
+//! | Wait for confirmations and detect reverts           | [`CctpV2Bridge::burn_and_confirm`]  |
+//! | Reject a burn or mint that would revert             | [`CctpV2Bridge::simulate_burn`]     |
+//! | Move more than the per-message burn limit           | [`CctpV2Bridge::transfer_chunked`]  |
+//! | Burn with a signed permit instead of `approve`      | [`CctpV2Bridge::burn_with_permit`]  |
+//! | Sign with a multisig, HSM or other external signer  | [`CctpV2Bridge::prepare_burn`]      |
+//! | Review and execute a burn in the Safe UI            | [`CctpV2Bridge::safe_burn_batch`]   |
+//! | Burn from an ERC-4337 smart account                 | [`CctpV2Bridge::burn_user_operation`] |
+//! | Set gas, nonce or fees, or plug in a fee strategy   | [`TxOptions`] + [`FeeStrategy`]     |
+//! | Re-send burns and mints stuck during fee spikes     | [`ReplacementPolicy`]               |
+//! | Burn concurrently from one account                  | [`NonceManager`]                    |
 //! | Inspect a v2 message as serializable JSON           | [`ParsedV2MessageSummary`]          |
 //! | Look up chain config without a provider             | [`CctpV1`] / [`CctpV2`] traits      |
+//! | Bridge on a fork, devnet or unlisted chain          | [`ChainRegistry`]                   |
+//! | Attest through a staging or mock Iris               | [`CctpEnvironment`]                 |
+//! | Find the chain of a message's destination domain    | [`DomainId::chain`]                 |
+//! | List supported routes and their capabilities        | [`ChainRegistry::routes`]           |
+//! | Export route ETAs and hooks as JSON or CSV          | [`RouteMatrix`]                     |
+//! | Check deployed contracts against the built-in tables | [`verify_chain_config`]            |
+//! | Check a registry record's deployed contracts        | [`verify_chain_record`]             |
 //! | Drive contracts directly                            | [`TokenMessengerV2Contract`] etc.   |
 //!
 //! For longer-form guidance and the full list of footguns see `AGENTS.md` in
@@ -118,16 +136,23 @@
 //! - [`Cctp`] and [`CctpV2Bridge`] - Core CCTP bridge implementations for v1 and v2
 //! - [`CctpV1`] and [`CctpV2`] - Traits for chain-specific configurations
 //! - [`PollingConfig`] - Configuration for attestation polling behavior
+//! - [`Transfer`], [`TransferRecord`] and [`TransferStore`] - Resumable transfers persisted after every step
+//! - [`ReceiptSummary`] and [`RevertedTransaction`] - Confirmed-transaction facts and revert details
+//! - [`ContractRevert`] - Categorical revert reasons from pre-flight simulation
+//! - [`ChunkedTransfer`] and [`TransferChunk`] - Transfers split across several burns
+//! - [`SafeBatch`] - Safe Transaction Builder batches and `MultiSend` calldata
+//! - [`UserOperation`] and [`Bundler`] - ERC-4337 v0.7 user operations for smart-account burns
+//! - [`PermitRequest`] and [`AuthorizationRequest`] - EIP-2612 / EIP-3009 typed data for USDC
 //! - [`ParsedV2Message`] and [`ParsedV2MessageSummary`] - Parse canonical v2 messages into serializable structs
 //! - [`ParseMessageError`] - Error type for canonical v2 message parsing
 //! - [`InvalidDomainId`] and [`InvalidFinalityThreshold`] - Errors returned by `TryFrom<u32>` for [`DomainId`] / [`FinalityThreshold`]
 //! - [`CctpError`] and [`Result`] - Error types for error handling
 //! - Contract wrappers for direct contract interaction:
 //!   - v1: [`TokenMessengerContract`], [`MessageTransmitterContract`]
-//!   - v2: [`TokenMessengerV2Contract`], [`MessageTransmitterV2Contract`]
+//!   - v2: [`TokenMessengerV2Contract`], [`MessageTransmitterV2Contract`], [`TokenMinterV2Contract`]
 //! - `sol!`-generated modules for decoding raw event logs against the canonical ABI:
 //!   - v1: [`TokenMessenger`], [`MessageTransmitter`]
-//!   - v2: [`TokenMessengerV2`], [`MessageTransmitterV2`]
+//!   - v2: [`TokenMessengerV2`], [`MessageTransmitterV2`], [`TokenMinterV2`]
 //!
 //! For example, to decode a v2 `DepositForBurn` event log:
 //!
@@ -148,31 +173,43 @@ mod contracts;
 mod error;
 mod protocol;
 mod provider;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 // Public API - minimal surface for 1.0.0 stability
 pub use bridge::{
-    batch_token_state, Cctp, CctpBridge, CctpV2 as CctpV2Bridge, MintResult, PollingConfig,
-    TokenState,
+    batch_token_state, encode_multi_send, simple_account_execute_batch, verify_chain_config,
+    verify_chain_record, BufferedFees, Bundler, Cctp, CctpBridge, CctpV2 as CctpV2Bridge,
+    ChainConfigReport, ChunkedTransfer, ConfigMismatch, FeeStrategy, FixedFees, HttpBundler,
+    InMemoryTransferStore, MintResult, NonceManager, PackedUserOperation, PercentileFees,
+    PollingConfig, ReceiptSummary, ReplacementPolicy, SafeBatch, SafeBatchMeta, SafeTransaction,
+    TokenState, Transfer, TransferChunk, TransferRecord, TransferState, TransferStore, TxOptions,
+    UserOperation, ENTRY_POINT_V07, MAX_BURN_CHUNKS, MULTI_SEND_CALL_ONLY, PERMIT_BURN_GAS_LIMIT,
 };
 pub use chain::addresses::{
     CCTP_V2_MESSAGE_TRANSMITTER_MAINNET, CCTP_V2_MESSAGE_TRANSMITTER_TESTNET,
     CCTP_V2_TOKEN_MESSENGER_MAINNET, CCTP_V2_TOKEN_MESSENGER_TESTNET,
 };
-pub use chain::{CctpV1, CctpV2};
+pub use chain::{
+    CctpEnvironment, CctpV1, CctpV2, ChainRecord, ChainRegistry, Route, RouteCapability,
+    RouteMatrix, V1Deployment, V2Deployment, CCTP_V2_MESSAGE_BODY_VERSION, CCTP_V2_MESSAGE_VERSION,
+};
 pub use contracts::{
     erc20::Erc20Contract,
     message_transmitter::{MessageTransmitter, MessageTransmitterContract},
     token_messenger::{TokenMessenger, TokenMessengerContract},
     v2::{
         MessageTransmitterV2, MessageTransmitterV2Contract, TokenMessengerV2,
-        TokenMessengerV2Contract,
+        TokenMessengerV2Contract, TokenMinterV2, TokenMinterV2Contract,
     },
 };
-pub use error::{AttestationFailureKind, CctpError, Result};
+pub use error::{AttestationFailureKind, CctpError, ContractRevert, Result, RevertedTransaction};
 pub use protocol::{
-    AttestationBytes, AttestationResponse, AttestationStatus, BurnMessageV2, DomainId,
-    FinalityThreshold, InvalidDomainId, InvalidFinalityThreshold, MessageHeader, ParseMessageError,
-    ParsedV2Message, ParsedV2MessageSummary, V2AttestationResponse, V2Message,
+    usdc_eip712_domain, AttestationBytes, AttestationResponse, AttestationStatus,
+    AuthorizationRequest, BurnMessageV2, DomainId, FinalityThreshold, InvalidDomainId,
+    InvalidFinalityThreshold, MessageHeader, ParseMessageError, ParsedV2Message,
+    ParsedV2MessageSummary, Permit, PermitRequest, ReceiveWithAuthorization,
+    TransferWithAuthorization, V2AttestationResponse, V2Message, USDC_EIP712_VERSION,
 };
 pub use provider::{
     calculate_gas_price_with_buffer, estimate_gas_with_buffer, ProviderConfig,
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! EIP-2612 permits and EIP-3009 transfer authorizations for USDC.
+//!
+//! USDC (`FiatTokenV2`) accepts an off-chain signature in place of an
+//! on-chain `approve`. This module builds the EIP-712 typed data to sign;
+//! the signature itself is produced by the caller's signer.
+
+use alloy_primitives::{Address, Signature, B256, U256};
+use alloy_sol_types::{sol, Eip712Domain, SolStruct};
+
+/// Version of USDC's EIP-712 domain, shared by every `FiatTokenV2` deployment.
+pub const USDC_EIP712_VERSION: &str = "2";
+
+sol! {
+    /// EIP-2612 `Permit` typed data, authorising `spender` to move `value`.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    /// EIP-3009 authorization for anyone to move `value` from `from` to `to`.
+    #[derive(Debug, PartialEq, Eq)]
+    struct TransferWithAuthorization {
+        address from;
+        address to;
+        uint256 value;
+        uint256 validAfter;
+        uint256 validBefore;
+        bytes32 nonce;
+    }
+
+    /// EIP-3009 authorization that only `to` may submit, which prevents
+    /// front-running when a helper contract pulls the funds.
+    #[derive(Debug, PartialEq, Eq)]
+    struct ReceiveWithAuthorization {
+        address from;
+        address to;
+        uint256 value;
+        uint256 validAfter;
+        uint256 validBefore;
+        bytes32 nonce;
+    }
+}
+
+/// Builds USDC's EIP-712 domain for a deployment.
+///
+/// `name` is the token's `name()`, which differs between chains (for example
+/// `"USD Coin"` on Ethereum and `"USDC"` on several newer chains), so read it
+/// from the token rather than hard-coding it.
+pub fn usdc_eip712_domain(name: impl Into<String>, chain_id: u64, token: Address) -> Eip712Domain {
+    Eip712Domain::new(
+        Some(name.into().into()),
+        Some(USDC_EIP712_VERSION.into()),
+        Some(U256::from(chain_id)),
+        Some(token),
+        None,
+    )
+}
+
+/// An unsigned EIP-2612 permit together with the domain it is signed under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermitRequest {
+    /// The token's EIP-712 domain
+    pub domain: Eip712Domain,
+    /// The permit to sign
+    pub permit: Permit,
+}
+
+impl PermitRequest {
+    /// The EIP-712 digest the owner must sign
+    pub fn signing_hash(&self) -> B256 {
+        self.permit.eip712_signing_hash(&self.domain)
+    }
+}
+
+/// An unsigned EIP-3009 authorization together with the domain it is signed under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationRequest {
+    /// The token's EIP-712 domain
+    pub domain: Eip712Domain,
+    /// The authorization to sign
+    pub authorization: ReceiveWithAuthorization,
+}
+
+impl AuthorizationRequest {
+    /// The EIP-712 digest the token holder must sign
+    pub fn signing_hash(&self) -> B256 {
+        self.authorization.eip712_signing_hash(&self.domain)
+    }
+}
+
+/// Splits a signature into the `(v, r, s)` arguments `FiatTokenV2` expects.
+pub(crate) fn signature_vrs(signature: &Signature) -> (u8, B256, B256) {
+    (
+        27 + u8::from(signature.v()),
+        signature.r().into(),
+        signature.s().into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, keccak256};
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[test]
+    fn test_type_hashes_match_fiat_token() {
+        assert_eq!(
+            keccak256(Permit::eip712_encode_type().as_bytes()),
+            b256!("6e71edae12b1b97f4d1f60370fef10105fa2faae0126114a169c64845d6126c9")
+        );
+        assert_eq!(
+            keccak256(TransferWithAuthorization::eip712_encode_type().as_bytes()),
+            b256!("7c7c6cdb67a18743f49ec6fa9b35f50d52ed05cbed4cc592e13b44501c1a2267")
+        );
+        assert_eq!(
+            keccak256(ReceiveWithAuthorization::eip712_encode_type().as_bytes()),
+            b256!("d099cc98ef71107a616c4f0f941f04c322d8e254fe26b3c6668db87aae413de8")
+        );
+    }
+
+    #[test]
+    fn test_usdc_domain_separator_on_ethereum() {
+        // DOMAIN_SEPARATOR() of USDC on Ethereum mainnet
+        let domain = usdc_eip712_domain(
+            "USD Coin",
+            1,
+            address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+        );
+        assert_eq!(
+            domain.separator(),
+            b256!("06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335")
+        );
+    }
+
+    #[test]
+    fn test_signed_permit_recovers_owner() {
+        let signer = PrivateKeySigner::random();
+        let request = PermitRequest {
+            domain: usdc_eip712_domain("USDC", 8453, Address::repeat_byte(0x11)),
+            permit: Permit {
+                owner: signer.address(),
+                spender: Address::repeat_byte(0x22),
+                value: U256::from(1_000_000),
+                nonce: U256::ZERO,
+                deadline: U256::MAX,
+            },
+        };
+
+        let signature = signer.sign_hash_sync(&request.signing_hash()).unwrap();
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&request.signing_hash())
+                .unwrap(),
+            signer.address()
+        );
+
+        let (v, r, s) = signature_vrs(&signature);
+        assert!(v == 27 || v == 28);
+        assert_eq!(U256::from_be_bytes(r.0), signature.r());
+        assert_eq!(U256::from_be_bytes(s.0), signature.s());
+    }
+}
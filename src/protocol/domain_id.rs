@@ -9,10 +9,14 @@
 //!
 //! Reference: <https://developers.circle.com/stablecoins/evm-smart-contracts>
 
+use alloy_chains::{Chain, NamedChain};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::chain::UNNAMED_CHAIN_IDS;
+use crate::{CctpEnvironment, CctpV2, ChainRecord};
+
 /// CCTP domain identifier for blockchain networks
 ///
 /// Each blockchain network supported by Circle's CCTP has a unique domain ID.
@@ -194,6 +198,54 @@ impl DomainId {
     pub const fn is_evm(self) -> bool {
         !matches!(self, Self::Solana | Self::StarknetTestnet)
     }
+
+    /// Returns the built-in chain of this domain in `environment`
+    ///
+    /// Use it to pick a provider for a parsed message's destination domain.
+    /// Chains without a `NamedChain` variant, such as Codex, are returned by
+    /// chain id. Returns `None` for non-EVM domains and any custom
+    /// environment; look those up with
+    /// [`ChainRegistry::domain_record`](crate::ChainRegistry::domain_record).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alloy_chains::{Chain, NamedChain};
+    /// use cctp_rs::{CctpEnvironment, DomainId};
+    ///
+    /// assert_eq!(
+    ///     DomainId::Base.chain(&CctpEnvironment::Mainnet),
+    ///     Some(Chain::from(NamedChain::Base))
+    /// );
+    /// assert_eq!(
+    ///     DomainId::Base.chain(&CctpEnvironment::Testnet),
+    ///     Some(Chain::from(NamedChain::BaseSepolia))
+    /// );
+    /// assert_eq!(
+    ///     DomainId::Codex.chain(&CctpEnvironment::Mainnet),
+    ///     Some(Chain::from_id(81224))
+    /// );
+    /// assert_eq!(DomainId::Solana.chain(&CctpEnvironment::Mainnet), None);
+    /// ```
+    pub fn chain(self, environment: &CctpEnvironment) -> Option<Chain> {
+        let named = NamedChain::iter().find(|chain| {
+            chain.supports_cctp_v2()
+                && chain.cctp_v2_domain_id().ok() == Some(self)
+                && chain.environment() == *environment
+        });
+        match named {
+            Some(named) => Some(named.into()),
+            None => UNNAMED_CHAIN_IDS
+                .into_iter()
+                .filter_map(ChainRecord::from_chain_id)
+                .find(|record| {
+                    record.domain == self
+                        && record.environment == *environment
+                        && record.supports_cctp_v2()
+                })
+                .map(|record| Chain::from_id(record.chain_id)),
+        }
+    }
 }
 
 impl From<DomainId> for u32 {
@@ -285,6 +337,32 @@ mod tests {
         assert_eq!(DomainId::from_u32(26), Some(DomainId::ArcTestnet));
     }
 
+    #[test]
+    fn test_chain_round_trips_through_domain_id() {
+        for chain in NamedChain::iter().filter(|chain| chain.supports_cctp_v2()) {
+            let domain = chain.cctp_v2_domain_id().unwrap();
+            assert_eq!(
+                domain.chain(&chain.environment()),
+                Some(chain.into()),
+                "{chain}"
+            );
+        }
+
+        assert_eq!(
+            DomainId::ArcTestnet.chain(&CctpEnvironment::Testnet),
+            Some(NamedChain::ArcTestnet.into())
+        );
+        assert_eq!(DomainId::ArcTestnet.chain(&CctpEnvironment::Mainnet), None);
+        assert_eq!(
+            DomainId::Codex.chain(&CctpEnvironment::Mainnet),
+            Some(Chain::from_id(81_224))
+        );
+        assert_eq!(
+            DomainId::Codex.chain(&CctpEnvironment::Testnet),
+            Some(Chain::from_id(812_242))
+        );
+    }
+
     #[test]
     fn test_from_u32_invalid() {
         // Test gaps in domain ID space
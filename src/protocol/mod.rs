@@ -8,6 +8,7 @@
 //! and v2-specific types like finality thresholds and message formats.
 
 mod attestation;
+mod authorization;
 mod domain_id;
 mod finality;
 mod message;
@@ -15,6 +16,11 @@ mod message;
 pub use attestation::{
     AttestationBytes, AttestationResponse, AttestationStatus, V2AttestationResponse, V2Message,
 };
+pub(crate) use authorization::signature_vrs;
+pub use authorization::{
+    usdc_eip712_domain, AuthorizationRequest, Permit, PermitRequest, ReceiveWithAuthorization,
+    TransferWithAuthorization, USDC_EIP712_VERSION,
+};
 pub use domain_id::{DomainId, InvalidDomainId};
 pub use finality::{FinalityThreshold, InvalidFinalityThreshold};
 pub use message::{
@@ -79,10 +79,9 @@
 //! | Default | `ProviderConfig::default()` | 3 retries, 30s timeout |
 
 use crate::error::Result;
-use alloy_network::Ethereum;
+use alloy_network::Network;
 use alloy_primitives::U256;
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
 use std::time::Duration;
 
 /// Default gas buffer percentage (20%)
@@ -102,7 +101,7 @@ pub const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
 ///
 /// # Arguments
 ///
-/// * `provider` - The Ethereum provider to use for estimation
+/// * `provider` - The provider to use for estimation, on any network
 /// * `tx` - The transaction request to estimate gas for
 /// * `buffer_percent` - Optional percentage buffer to add (defaults to 20%)
 ///
@@ -118,9 +117,9 @@ pub const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
 /// let gas_limit = estimate_gas_with_buffer(&provider, &tx, Some(20)).await?;
 /// let tx = tx.with_gas_limit(gas_limit);
 /// ```
-pub async fn estimate_gas_with_buffer<P: Provider<Ethereum>>(
+pub async fn estimate_gas_with_buffer<P: Provider<N>, N: Network>(
     provider: &P,
-    tx: &TransactionRequest,
+    tx: &N::TransactionRequest,
     buffer_percent: Option<u64>,
 ) -> Result<u64> {
     let buffer = buffer_percent.unwrap_or(DEFAULT_GAS_BUFFER_PERCENT);
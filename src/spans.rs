@@ -18,14 +18,14 @@
 //! ```rust,no_run
 //! use cctp_rs::spans;
 //! use alloy_primitives::FixedBytes;
-//! use alloy_chains::NamedChain;
+//! use alloy_chains::{Chain, NamedChain};
 //!
 //! // Create a span for attestation polling
 //! let message_hash = FixedBytes::from([0u8; 32]);
 //! let span = spans::get_attestation_with_retry(
 //!     &message_hash,
-//!     &NamedChain::Mainnet,
-//!     &NamedChain::Arbitrum,
+//!     &Chain::from(NamedChain::Mainnet),
+//!     &Chain::from(NamedChain::Arbitrum),
 //!     30,  // max attempts
 //!     60,  // poll interval
 //! );
@@ -33,7 +33,7 @@
 //! // Your custom attestation logic here
 //! ```
 
-use alloy_chains::NamedChain;
+use alloy_chains::Chain;
 use alloy_primitives::{Address, FixedBytes, TxHash, U256};
 use tracing::Span;
 use url::Url;
@@ -44,8 +44,8 @@ use url::Url;
 /// Children: Provider RPC calls (from alloy instrumentation)
 pub fn get_message_sent_event(
     tx_hash: TxHash,
-    source_chain: &NamedChain,
-    destination_chain: &NamedChain,
+    source_chain: &Chain,
+    destination_chain: &Chain,
 ) -> Span {
     tracing::info_span!(
         "cctp_rs.get_message_sent_event",
@@ -69,8 +69,8 @@ pub fn get_message_sent_event(
 /// Children: `cctp_rs.get_attestation` (multiple attempts)
 pub fn get_attestation_with_retry(
     message_hash: &FixedBytes<32>,
-    source_chain: &NamedChain,
-    destination_chain: &NamedChain,
+    source_chain: &Chain,
+    destination_chain: &Chain,
     max_attempts: u32,
     poll_interval_secs: u64,
 ) -> Span {
@@ -96,8 +96,8 @@ pub fn get_attestation_with_retry(
 /// Children: `cctp_rs.get_attestation` (multiple attempts)
 pub fn get_v2_attestation_with_retry(
     tx_hash: TxHash,
-    source_chain: &NamedChain,
-    destination_chain: &NamedChain,
+    source_chain: &Chain,
+    destination_chain: &Chain,
     max_attempts: u32,
     poll_interval_secs: u64,
 ) -> Span {
@@ -169,7 +169,7 @@ pub fn deposit_for_burn(
 ///
 /// Parent: Operation span (e.g., `deposit_for_burn`)
 /// Children: Provider RPC calls
-pub fn send_transaction(tx_hash: TxHash, source_chain: &NamedChain) -> Span {
+pub fn send_transaction(tx_hash: TxHash, source_chain: &Chain) -> Span {
     tracing::debug_span!(
         "cctp_rs.send_transaction",
         tx_hash = %tx_hash,
@@ -181,11 +181,7 @@ pub fn send_transaction(tx_hash: TxHash, source_chain: &NamedChain) -> Span {
 ///
 /// Parent: `send_transaction` or top-level operation
 /// Children: Provider RPC calls (polling)
-pub fn wait_for_confirmation(
-    tx_hash: TxHash,
-    chain: &NamedChain,
-    required_confirmations: u64,
-) -> Span {
+pub fn wait_for_confirmation(tx_hash: TxHash, chain: &Chain, required_confirmations: u64) -> Span {
     tracing::debug_span!(
         "cctp_rs.wait_for_confirmation",
         tx_hash = %tx_hash,
@@ -200,7 +196,7 @@ pub fn wait_for_confirmation(
 /// Children: Contract interaction spans, RPC calls
 pub fn receive_message(
     message_hash: &FixedBytes<32>,
-    destination_chain: &NamedChain,
+    destination_chain: &Chain,
     attestation_length: usize,
 ) -> Span {
     tracing::info_span!(
@@ -228,7 +224,7 @@ pub fn http_request(method: &str, url: &Url, request_id: Option<&str>) -> Span {
 ///
 /// Parent: Operation span (`get_message_sent_event`, `wait_for_confirmation`, etc.)
 /// Children: None (provider handles internal spans)
-pub fn rpc_call(method: &str, chain: &NamedChain, params_summary: &str) -> Span {
+pub fn rpc_call(method: &str, chain: &Chain, params_summary: &str) -> Span {
     tracing::trace_span!(
         "cctp_rs.rpc_call",
         rpc.method = method,
@@ -241,7 +237,7 @@ pub fn rpc_call(method: &str, chain: &NamedChain, params_summary: &str) -> Span
 ///
 /// Parent: `get_message_sent_event` or other receipt operations
 /// Children: RPC calls
-pub fn get_transaction_receipt(tx_hash: TxHash, chain: &NamedChain) -> Span {
+pub fn get_transaction_receipt(tx_hash: TxHash, chain: &Chain) -> Span {
     tracing::debug_span!(
         "cctp_rs.get_transaction_receipt",
         tx_hash = %tx_hash,
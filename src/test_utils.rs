@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fixtures shared by the unit tests.
+
+use std::fmt::Debug;
+
+use alloy_chains::NamedChain;
+use alloy_network::Ethereum;
+use alloy_primitives::{Address, Bytes};
+use alloy_provider::mock::Asserter;
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_sol_types::SolValue;
+
+use crate::CctpV2Bridge;
+
+/// A provider answering from the returned `Asserter`'s queue, without fillers
+pub(crate) fn mocked() -> (Asserter, impl Provider<Ethereum> + Clone + Debug) {
+    let asserter = Asserter::new();
+    let provider = ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .connect_mocked_client(asserter.clone());
+    (asserter, provider)
+}
+
+/// ABI-encoded `value`, as an `eth_call` returns it
+pub(crate) fn word<T: SolValue>(value: T) -> Bytes {
+    value.abi_encode().into()
+}
+
+/// A Mainnet to Base bridge on an unreachable HTTP provider, for tests that
+/// only build calldata
+pub(crate) fn offline_bridge() -> CctpV2Bridge<impl Provider<Ethereum> + Clone> {
+    let provider = ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
+    CctpV2Bridge::builder()
+        .source_chain(NamedChain::Mainnet)
+        .destination_chain(NamedChain::Base)
+        .source_provider(provider.clone())
+        .destination_provider(provider)
+        .recipient(Address::repeat_byte(0x02))
+        .build()
+}